@@ -0,0 +1,416 @@
+//! 本地的 pixiv API 替身服务, 用于集成测试
+//!
+//! 提供 /auth/token、/v1/illust/*、/v1/user/* 等接口的固定响应以及图片服务,
+//! 并可以注入延迟、429、5xx 和截断的响应体。
+//!
+//! 可以作为命令行程序运行(见 main.rs), 也可以在测试中用 serve 启动在任意端口。
+//! 客户端通过 set_server_urls 将 app / oauth / image 地址都指向 http://127.0.0.1:8989 即可。
+//! 运行时可以 POST /_faults 修改故障注入参数(json, 字段同 Faults), GET /_requests 查看收到的请求。
+
+use anyhow::Result;
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{header, HeaderMap, Method, StatusCode, Uri};
+use axum::response::{IntoResponse, Response};
+use axum::Router;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+const PAGE_SIZE: u64 = 30;
+const PAGE_COUNT: u64 = 3;
+
+/// 1x1 的png
+const PIXEL_PNG: &[u8] = &[
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44,
+    0x52, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1f,
+    0x15, 0xc4, 0x89, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0xf8,
+    0xcf, 0xc0, 0xf0, 0x1f, 0x00, 0x05, 0x00, 0x01, 0xff, 0x89, 0x99, 0x3d, 0x1d, 0x00, 0x00,
+    0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+];
+
+/// 故障注入参数, 0 表示不启用
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Faults {
+    #[serde(default)]
+    pub latency_ms: u64,
+    // 每 N 个请求返回一次 fail_status
+    #[serde(default)]
+    pub fail_every: u64,
+    #[serde(default)]
+    pub fail_status: u16,
+    // 每 N 个请求返回一次截断的响应体
+    #[serde(default)]
+    pub truncate_every: u64,
+}
+
+/// 覆盖默认响应的录制数据, 格式与客户端的 FixtureBackend 相同
+#[derive(Debug, Clone, Deserialize)]
+pub struct Fixture {
+    pub method: String,
+    pub path: String,
+    pub status: u16,
+    pub body: String,
+}
+
+struct AppState {
+    faults: Mutex<Faults>,
+    fixtures: Vec<Fixture>,
+    counter: AtomicU64,
+    requests: Mutex<Vec<String>>,
+}
+
+/// 在 listener 上提供服务, 直到出错
+pub async fn serve(listener: tokio::net::TcpListener, faults: Faults, fixtures: Vec<Fixture>) -> Result<()> {
+    let state = Arc::new(AppState {
+        faults: Mutex::new(faults),
+        fixtures,
+        counter: AtomicU64::new(0),
+        requests: Mutex::new(vec![]),
+    });
+    let app = Router::new().fallback(handle).with_state(state);
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+pub fn load_fixtures(dir: &str) -> Result<Vec<Fixture>> {
+    let mut fixtures = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == "json") {
+            fixtures.push(serde_json::from_str(&std::fs::read_to_string(path)?)?);
+        }
+    }
+    Ok(fixtures)
+}
+
+async fn handle(
+    State(state): State<Arc<AppState>>,
+    method: Method,
+    uri: Uri,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let path_and_query = uri
+        .path_and_query()
+        .map(|p| p.as_str().to_owned())
+        .unwrap_or_default();
+    match (method.as_str(), uri.path()) {
+        ("POST", "/_faults") => {
+            return match serde_json::from_slice::<Faults>(&body) {
+                Ok(faults) => {
+                    *state.faults.lock().unwrap() = faults;
+                    StatusCode::NO_CONTENT.into_response()
+                }
+                Err(err) => (StatusCode::BAD_REQUEST, err.to_string()).into_response(),
+            };
+        }
+        ("GET", "/_requests") => {
+            let requests = state.requests.lock().unwrap().clone();
+            return json_response(StatusCode::OK, json!(requests));
+        }
+        _ => {}
+    }
+    state
+        .requests
+        .lock()
+        .unwrap()
+        .push(format!("{} {}", method, path_and_query));
+
+    let n = state.counter.fetch_add(1, Ordering::SeqCst) + 1;
+    let faults = state.faults.lock().unwrap().clone();
+    if faults.latency_ms > 0 {
+        tokio::time::sleep(Duration::from_millis(faults.latency_ms)).await;
+    }
+    if faults.fail_every > 0 && n % faults.fail_every == 0 {
+        return fault_response(faults.fail_status);
+    }
+
+    let base = format!(
+        "http://{}",
+        headers
+            .get(header::HOST)
+            .and_then(|h| h.to_str().ok())
+            .unwrap_or("127.0.0.1")
+    );
+    let response = match fixture_response(&state.fixtures, &method, &path_and_query) {
+        Some(response) => response,
+        None => route(&method, &uri, &base, &body),
+    };
+    if faults.truncate_every > 0 && n % faults.truncate_every == 0 {
+        return truncate(response).await;
+    }
+    response
+}
+
+fn fixture_response(fixtures: &[Fixture], method: &Method, path: &str) -> Option<Response> {
+    let fixture = fixtures
+        .iter()
+        .filter(|f| f.method.eq_ignore_ascii_case(method.as_str()))
+        .filter(|f| path.starts_with(&f.path))
+        .max_by_key(|f| f.path.len())?;
+    let status = StatusCode::from_u16(fixture.status).unwrap_or(StatusCode::OK);
+    Some(
+        (
+            status,
+            [(header::CONTENT_TYPE, "application/json")],
+            fixture.body.clone(),
+        )
+            .into_response(),
+    )
+}
+
+fn fault_response(status: u16) -> Response {
+    let status = StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    let body = app_error("Rate Limit", "injected fault");
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return (status, [(header::RETRY_AFTER, "1")], body.to_string()).into_response();
+    }
+    (status, body.to_string()).into_response()
+}
+
+/// 只发送一半响应体后断开连接, 客户端会在读取时出错
+async fn truncate(response: Response) -> Response {
+    let (mut parts, body) = response.into_parts();
+    let bytes = axum::body::to_bytes(body, usize::MAX).await.unwrap_or_default();
+    let half = bytes.slice(0..bytes.len() / 2);
+    parts.headers.insert(
+        header::CONTENT_LENGTH,
+        bytes.len().max(1).to_string().parse().unwrap(),
+    );
+    let chunks: Vec<std::io::Result<Bytes>> = vec![
+        Ok(half),
+        Err(std::io::Error::other("injected truncated body")),
+    ];
+    Response::from_parts(
+        parts,
+        axum::body::Body::from_stream(futures_util::stream::iter(chunks)),
+    )
+}
+
+fn json_response(status: StatusCode, value: Value) -> Response {
+    (
+        status,
+        [(header::CONTENT_TYPE, "application/json")],
+        value.to_string(),
+    )
+        .into_response()
+}
+
+fn app_error(reason: &str, message: &str) -> Value {
+    json!({
+        "error": {
+            "user_message": "",
+            "message": message,
+            "reason": reason,
+            "user_message_details": {},
+        }
+    })
+}
+
+fn query_param(uri: &Uri, key: &str) -> Option<String> {
+    uri.query()?.split('&').find_map(|kv| {
+        let (k, v) = kv.split_once('=')?;
+        (k == key).then(|| v.to_owned())
+    })
+}
+
+fn route(method: &Method, uri: &Uri, base: &str, body: &Bytes) -> Response {
+    let path = uri.path();
+    match (method.as_str(), path) {
+        ("POST", "/auth/token") => auth_token(body),
+        ("GET", "/v1/illust/recommended")
+        | ("GET", "/v1/illust/ranking")
+        | ("GET", "/v1/search/illust")
+        | ("GET", "/v1/user/illusts")
+        | ("GET", "/v1/user/bookmarks/illust") => json_response(StatusCode::OK, illust_page(uri, base)),
+        ("GET", "/v1/trending-tags/illust") => json_response(
+            StatusCode::OK,
+            json!({
+                "trend_tags": (1..=10).map(|i| json!({
+                    "tag": format!("tag{}", i),
+                    "translated_name": null,
+                    "illust": illust(i),
+                })).collect::<Vec<_>>(),
+            }),
+        ),
+        ("GET", "/v1/user/detail") => {
+            let user_id: i64 = query_param(uri, "user_id")
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(1);
+            json_response(StatusCode::OK, user_detail(user_id))
+        }
+        ("GET", "/v1/user/following") => json_response(
+            StatusCode::OK,
+            json!({
+                "user_previews": (1..=10).map(|i| json!({
+                    "user": user_sample(i),
+                    "illusts": [illust(i * 100)],
+                    "is_muted": false,
+                })).collect::<Vec<_>>(),
+                "next_url": null,
+            }),
+        ),
+        ("POST", "/v1/user/follow/add")
+        | ("POST", "/v1/user/follow/delete")
+        | ("POST", "/v2/illust/bookmark/add")
+        | ("POST", "/v1/illust/bookmark/delete") => json_response(StatusCode::OK, json!({})),
+        ("GET", p) if p.starts_with("/img-") || p.starts_with("/c/") || p.starts_with("/user-profile/") => (
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, "image/png")],
+            PIXEL_PNG,
+        )
+            .into_response(),
+        _ => json_response(StatusCode::NOT_FOUND, app_error("", "Not Found")),
+    }
+}
+
+fn auth_token(body: &Bytes) -> Response {
+    let form = String::from_utf8_lossy(body);
+    let param = |key: &str| {
+        form.split('&').find_map(|kv| {
+            let (k, v) = kv.split_once('=')?;
+            (k == key).then(|| v.to_owned())
+        })
+    };
+    let valid = match param("grant_type").as_deref() {
+        Some("authorization_code") => param("code").is_some_and(|c| c != "invalid"),
+        Some("refresh_token") => param("refresh_token").is_some_and(|t| t != "revoked"),
+        _ => false,
+    };
+    if !valid {
+        return json_response(
+            StatusCode::BAD_REQUEST,
+            json!({
+                "has_error": true,
+                "errors": {"system": {"message": "Invalid refresh token", "code": 1508}},
+                "error": "invalid_grant",
+            }),
+        );
+    }
+    json_response(
+        StatusCode::OK,
+        json!({
+            "access_token": "fake-access-token",
+            "expires_in": 3600,
+            "token_type": "bearer",
+            "scope": "",
+            "refresh_token": "fake-refresh-token",
+            "user": {
+                "profile_image_urls": {
+                    "px_16x16": "https://i.pximg.net/user-profile/img/16.png",
+                    "px_50x50": "https://i.pximg.net/user-profile/img/50.png",
+                    "px_170x170": "https://i.pximg.net/user-profile/img/170.png",
+                },
+                "id": "1",
+                "name": "fake",
+                "account": "fake",
+                "mail_address": "fake@example.com",
+                "is_premium": false,
+                "x_restrict": 0,
+                "is_mail_authorized": true,
+            },
+        }),
+    )
+}
+
+/// 按 offset 分页, 共 PAGE_COUNT 页
+fn illust_page(uri: &Uri, base: &str) -> Value {
+    let offset: u64 = query_param(uri, "offset")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0);
+    let illusts: Vec<Value> = (offset..offset + PAGE_SIZE)
+        .map(|i| illust(i as i64 + 1))
+        .collect();
+    let next_offset = offset + PAGE_SIZE;
+    let next_url = if next_offset < PAGE_SIZE * PAGE_COUNT {
+        let query: Vec<String> = uri
+            .query()
+            .unwrap_or_default()
+            .split('&')
+            .filter(|kv| !kv.is_empty() && !kv.starts_with("offset="))
+            .map(|kv| kv.to_owned())
+            .chain(std::iter::once(format!("offset={}", next_offset)))
+            .collect();
+        json!(format!("{}{}?{}", base, uri.path(), query.join("&")))
+    } else {
+        Value::Null
+    };
+    json!({ "illusts": illusts, "next_url": next_url })
+}
+
+fn user_sample(id: i64) -> Value {
+    json!({
+        "id": id,
+        "name": format!("user{}", id),
+        "account": format!("user{}", id),
+        "profile_image_urls": {"medium": "https://i.pximg.net/user-profile/img/170.png"},
+        "is_followed": false,
+    })
+}
+
+fn illust(id: i64) -> Value {
+    let master = format!(
+        "https://i.pximg.net/c/540x540_70/img-master/img/2024/01/01/00/00/00/{}_p0_master1200.jpg",
+        id
+    );
+    json!({
+        "id": id,
+        "title": format!("illust{}", id),
+        "type": "illust",
+        "image_urls": {"square_medium": master, "medium": master, "large": master},
+        "caption": "",
+        "restrict": 0,
+        "user": user_sample(id % 10 + 1),
+        "tags": [{"name": format!("tag{}", id % 10 + 1), "translated_name": null}],
+        "tools": [],
+        "create_date": "2024-01-01T00:00:00+09:00",
+        "page_count": 1,
+        "width": 1,
+        "height": 1,
+        "sanity_level": 2,
+        "x_restrict": 0,
+        "series": null,
+        "meta_single_page": {
+            "original_image_url": format!("https://i.pximg.net/img-original/img/2024/01/01/00/00/00/{}_p0.png", id),
+        },
+        "meta_pages": [],
+        "total_view": 100,
+        "total_bookmarks": 10,
+        "is_bookmarked": false,
+        "visible": true,
+        "is_muted": false,
+        "illust_ai_type": 1,
+    })
+}
+
+fn user_detail(user_id: i64) -> Value {
+    json!({
+        "profile": {
+            "address_id": 0, "background_image_url": null, "birth": "", "birth_day": "",
+            "birth_year": 0, "country_code": "", "gender": "", "is_premium": false,
+            "is_using_custom_profile_image": false, "job": "", "job_id": 0, "pawoo_url": null,
+            "region": "", "total_follow_users": 0, "total_illust_bookmarks_public": 0,
+            "total_illust_series": 0, "total_illusts": PAGE_SIZE * PAGE_COUNT, "total_manga": 0,
+            "total_mypixiv_users": 0, "total_novel_series": 0, "total_novels": 0,
+            "twitter_account": "", "twitter_url": null, "webpage": null,
+        },
+        "profile_publicity": {
+            "birth_day": "public", "birth_year": "public", "gender": "public",
+            "job": "public", "pawoo": true, "region": "public",
+        },
+        "user": {
+            "account": format!("user{}", user_id), "comment": "", "id": user_id,
+            "is_access_blocking_user": false, "is_followed": false,
+            "name": format!("user{}", user_id),
+            "profile_image_urls": {"medium": "https://i.pximg.net/user-profile/img/170.png"},
+        },
+        "workspace": {
+            "chair": "", "comment": "", "desk": "", "desktop": "", "monitor": "", "mouse": "",
+            "music": "", "pc": "", "printer": "", "scanner": "", "tablet": "", "tool": "",
+            "workspace_image_url": null,
+        },
+    })
+}
@@ -0,0 +1,37 @@
+//! 本地的 pixiv API 替身服务
+//!
+//! 用法: fake-pixiv [--port 8989] [--fixtures DIR] [--latency-ms N]
+//!                  [--fail-every N] [--fail-status 429] [--truncate-every N]
+
+use anyhow::{Context, Result};
+use fake_server::{load_fixtures, serve, Faults};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut port: u16 = 8989;
+    let mut fixtures_dir: Option<String> = None;
+    let mut faults = Faults {
+        fail_status: 500,
+        ..Default::default()
+    };
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().with_context(|| format!("missing value of {}", arg));
+        match arg.as_str() {
+            "--port" => port = value()?.parse()?,
+            "--fixtures" => fixtures_dir = Some(value()?),
+            "--latency-ms" => faults.latency_ms = value()?.parse()?,
+            "--fail-every" => faults.fail_every = value()?.parse()?,
+            "--fail-status" => faults.fail_status = value()?.parse()?,
+            "--truncate-every" => faults.truncate_every = value()?.parse()?,
+            other => anyhow::bail!("unknown argument: {}", other),
+        }
+    }
+    let fixtures = match fixtures_dir {
+        Some(dir) => load_fixtures(&dir)?,
+        None => vec![],
+    };
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", port)).await?;
+    println!("fake pixiv server listening on http://127.0.0.1:{}", port);
+    serve(listener, faults, fixtures).await
+}
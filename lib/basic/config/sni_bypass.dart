@@ -9,10 +9,10 @@ final sniBypassSignal = signal<bool>(false);
 final sniBypassHostsSignal = signal<Map<String, String>>({});
 
 const defaultSniBypassHosts = <String, String>{
-  'app-api.pixiv.net': '210.140.139.155',
-  'oauth.secure.pixiv.net': '210.140.139.155',
-  'i.pximg.net': '210.140.139.133',
-  's.pximg.net': '210.140.139.133',
+  'app-api.pixiv.net': '210.140.139.155,210.140.131.199',
+  'oauth.secure.pixiv.net': '210.140.139.155,210.140.131.199',
+  'i.pximg.net': '210.140.139.133,210.140.92.143',
+  's.pximg.net': '210.140.139.133,210.140.92.140',
 };
 
 Future<void> initSniBypass() async {
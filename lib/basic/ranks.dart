@@ -1,44 +1,52 @@
+import 'package:flutter/widgets.dart';
+import 'package:flutter_gen/gen_l10n/app_localizations.dart';
+import 'package:pansy/src/rust/pixirust/entities.dart';
 
-const RankModeDay = "day";
-const RankModeWeek = "week";
-const RankModeMonth = "month";
-const RankModeDayMale = "day_male";
-const RankModeDayFemale = "day_female";
-const RankModeWeekOriginal = "week_original";
-const RankModeWeekRookie = "week_rookie";
-const RankModeDayR18 = "day_r18";
-const RankModeDayMaleR18 = "day_male_r18";
-const RankModeDayFemaleR18 = "day_female_r18";
-const RankModeWeekR18 = "week_r18";
-const RankModeWeekR18g = "week_r18g";
-const RankModeDayManga = "day_manga";
-const RankModeWeekManga = "week_manga";
-const RankModeMonthManga = "month_manga";
-const RankModeWeekRookieManga = "week_rookie_manga";
-const RankModeDayR18Manga = "day_r18_manga";
-const RankModeWeekR18Manga = "week_r18_manga";
-const RankModeWeekR18gManga = "week_r18g_manga";
-
-
-List<String> ranks = [
-  RankModeDay,
-  RankModeWeek,
-  RankModeMonth,
-  RankModeDayMale,
-  RankModeDayFemale,
-  RankModeWeekOriginal,
-  RankModeWeekRookie,
-  RankModeDayR18,
-  RankModeDayMaleR18,
-  RankModeDayFemaleR18,
-  RankModeWeekR18,
-  RankModeWeekR18g,
-  RankModeDayManga,
-  RankModeWeekManga,
-  RankModeMonthManga,
-  RankModeWeekRookieManga,
-  RankModeDayR18Manga,
-  RankModeWeekR18Manga,
-  RankModeWeekR18gManga,
+List<RankMode> ranks = [
+  RankMode.day,
+  RankMode.week,
+  RankMode.month,
+  RankMode.dayMale,
+  RankMode.dayFemale,
+  RankMode.weekOriginal,
+  RankMode.weekRookie,
+  RankMode.dayR18,
+  RankMode.dayMaleR18,
+  RankMode.dayFemaleR18,
+  RankMode.weekR18,
+  RankMode.weekR18G,
+  RankMode.dayManga,
+  RankMode.weekManga,
+  RankMode.monthManga,
+  RankMode.weekRookieManga,
+  RankMode.dayR18Manga,
+  RankMode.weekR18Manga,
+  RankMode.weekR18GManga,
 ];
 
+String rankModeName(BuildContext context, RankMode mode) {
+  final l10n = AppLocalizations.of(context)!;
+  return switch (mode) {
+    RankMode.day => l10n.rankDay,
+    RankMode.week => l10n.rankWeek,
+    RankMode.month => l10n.rankMonth,
+    RankMode.dayMale => l10n.rankDayMale,
+    RankMode.dayFemale => l10n.rankDayFemale,
+    RankMode.weekOriginal => l10n.rankWeekOriginal,
+    RankMode.weekRookie => l10n.rankWeekRookie,
+    RankMode.dayAi => l10n.rankDayAi,
+    RankMode.dayManga => l10n.rankDayManga,
+    RankMode.weekManga => l10n.rankWeekManga,
+    RankMode.monthManga => l10n.rankMonthManga,
+    RankMode.weekRookieManga => l10n.rankWeekRookieManga,
+    RankMode.dayR18 => l10n.rankDayR18,
+    RankMode.dayMaleR18 => l10n.rankDayMaleR18,
+    RankMode.dayFemaleR18 => l10n.rankDayFemaleR18,
+    RankMode.weekR18 => l10n.rankWeekR18,
+    RankMode.weekR18G => l10n.rankWeekR18G,
+    RankMode.dayR18Ai => l10n.rankDayR18Ai,
+    RankMode.dayR18Manga => l10n.rankDayR18Manga,
+    RankMode.weekR18Manga => l10n.rankWeekR18Manga,
+    RankMode.weekR18GManga => l10n.rankWeekR18GManga,
+  };
+}
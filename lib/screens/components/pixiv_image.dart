@@ -5,7 +5,6 @@ import 'package:flutter/material.dart';
 
 import 'dart:ui' as ui;
 
-import 'package:pansy/basic/config/picture_source.dart';
 import 'package:pansy/src/rust/api/api.dart';
 
 class PixivUrlImageProvider extends ImageProvider<PixivUrlImageProvider> {
@@ -29,9 +28,8 @@ class PixivUrlImageProvider extends ImageProvider<PixivUrlImageProvider> {
 
   Future<ui.Codec> _loadAsync(PixivUrlImageProvider key) async {
     assert(key == this);
-    final effectiveUrl = rewritePixivImageUrl(url);
     return ui.instantiateImageCodec(
-        await File(await loadPixivImage(url: effectiveUrl)).readAsBytes());
+        await File(await loadPixivImage(url: url)).readAsBytes());
   }
 
   @override
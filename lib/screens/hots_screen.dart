@@ -1,6 +1,7 @@
 import 'package:flutter/material.dart';
 import 'package:pansy/basic/ranks.dart';
 import '../src/rust/api/api.dart';
+import '../src/rust/pixirust/entities.dart';
 import '../src/rust/udto.dart';
 import 'components/content_builder.dart';
 import 'components/first_url_illust_flow.dart';
@@ -36,7 +37,9 @@ class _HotsScreenState extends State<HotsScreen>
       children: [
         TabBar(
           controller: _tabController,
-          tabs: ranks.map((e) => Tab(text: e)).toList(),
+          tabs: ranks
+              .map((e) => Tab(text: rankModeName(context, e)))
+              .toList(),
           isScrollable: true,
           labelColor: Colors.black,
         ),
@@ -53,7 +56,7 @@ class _HotsScreenState extends State<HotsScreen>
 }
 
 class _RankTab extends StatefulWidget {
-  final String mode;
+  final RankMode mode;
 
   const _RankTab({Key? key, required this.mode}) : super(key: key);
 
@@ -409,7 +409,7 @@ class _IllustInfoScreenState extends State<IllustInfoScreen> {
                             builder: (BuildContext context) {
                               return SearchResultScreen(
                                 query: e.name,
-                                mode: ILLUST_SEARCH_MODE_EXACT_MATCH_FOR_TAGS,
+                                mode: SearchTarget.exactMatchForTags,
                               );
                             },
                           ),
@@ -553,7 +553,7 @@ class _IllustInfoScreenState extends State<IllustInfoScreen> {
           );
         }
       } else {
-        await addBookmark(illustId: widget.illust.id, restrict: "public");
+        await addBookmark(illustId: widget.illust.id, restrict: Restrict.public);
         setState(() {
           _isBookmarked = true;
           _isBookmarkLoading = false;
@@ -135,7 +135,7 @@ class _RankingScreenState extends State<RankingScreen>
                 isScrollable: true,
                 tabAlignment: TabAlignment.start,
                 tabs: ranks
-                    .map((mode) => Tab(text: _getRankName(context, mode)))
+                    .map((mode) => Tab(text: rankModeName(context, mode)))
                     .toList(),
               ),
             ),
@@ -154,29 +154,10 @@ class _RankingScreenState extends State<RankingScreen>
       ),
     );
   }
-
-  String _getRankName(BuildContext context, String mode) {
-    final l10n = AppLocalizations.of(context)!;
-    return switch (mode) {
-      'day' => l10n.rankDay,
-      'week' => l10n.rankWeek,
-      'month' => l10n.rankMonth,
-      'day_male' => l10n.rankDayMale,
-      'day_female' => l10n.rankDayFemale,
-      'week_original' => l10n.rankWeekOriginal,
-      'week_rookie' => l10n.rankWeekRookie,
-      'day_r18' => l10n.rankDayR18,
-      'day_male_r18' => l10n.rankDayMaleR18,
-      'day_female_r18' => l10n.rankDayFemaleR18,
-      'week_r18' => l10n.rankWeekR18,
-      'week_r18g' => l10n.rankWeekR18G,
-      _ => mode,
-    };
-  }
 }
 
 class _RankingTab extends StatefulWidget {
-  final String mode;
+  final RankMode mode;
   final String date;
 
   const _RankingTab({super.key, required this.mode, required this.date});
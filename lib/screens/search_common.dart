@@ -1,33 +1,26 @@
 import 'package:flutter/material.dart';
 import 'package:flutter_gen/gen_l10n/app_localizations.dart';
 import 'package:pansy/basic/commons.dart';
+import 'package:pansy/src/rust/pixirust/entities.dart';
 
-const ILLUST_SEARCH_MODE_PARTIAL_MATCH_FOR_TAGS = "partial_match_for_tags";
-const ILLUST_SEARCH_MODE_EXACT_MATCH_FOR_TAGS = "exact_match_for_tags";
-const ILLUST_SEARCH_MODE_TITLE_AND_CAPTION = "title_and_caption";
-
-String tagModeNameAlias(String mode) {
-  switch (mode) {
-    case ILLUST_SEARCH_MODE_PARTIAL_MATCH_FOR_TAGS:
-      return "PT";
-    case ILLUST_SEARCH_MODE_EXACT_MATCH_FOR_TAGS:
-      return "ET";
-    case ILLUST_SEARCH_MODE_TITLE_AND_CAPTION:
-      return "TAC";
-  }
-  return "";
+String tagModeNameAlias(SearchTarget mode) {
+  return switch (mode) {
+    SearchTarget.partialMatchForTags => "PT",
+    SearchTarget.exactMatchForTags => "ET",
+    SearchTarget.titleAndCaption => "TAC",
+  };
 }
 
-Future<String?> chooseMode(BuildContext context) async {
+Future<SearchTarget?> chooseMode(BuildContext context) async {
   return chooseMapDialog(
     context,
     {
       AppLocalizations.of(context)!.partial_match_for_tags:
-          ILLUST_SEARCH_MODE_PARTIAL_MATCH_FOR_TAGS,
+          SearchTarget.partialMatchForTags,
       AppLocalizations.of(context)!.exact_match_for_tags:
-          ILLUST_SEARCH_MODE_EXACT_MATCH_FOR_TAGS,
+          SearchTarget.exactMatchForTags,
       AppLocalizations.of(context)!.title_and_caption:
-          ILLUST_SEARCH_MODE_TITLE_AND_CAPTION,
+          SearchTarget.titleAndCaption,
     },
     AppLocalizations.of(context)!.chooseMatchMode,
   );
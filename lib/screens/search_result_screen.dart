@@ -3,17 +3,18 @@ import 'package:flutter_gen/gen_l10n/app_localizations.dart';
 import 'package:pansy/screens/components/first_url_illust_flow.dart';
 import 'package:pansy/screens/search_common.dart';
 import 'package:pansy/src/rust/api/api.dart';
+import 'package:pansy/src/rust/pixirust/entities.dart';
 import 'package:pansy/src/rust/udto.dart';
 
 /// 搜索结果页面
 class SearchResultScreen extends StatefulWidget {
   final String query;
-  final String mode;
+  final SearchTarget mode;
 
   const SearchResultScreen({
     super.key,
     required this.query,
-    this.mode = ILLUST_SEARCH_MODE_PARTIAL_MATCH_FOR_TAGS,
+    this.mode = SearchTarget.partialMatchForTags,
   });
 
   @override
@@ -24,7 +25,7 @@ class _SearchResultScreenState extends State<SearchResultScreen>
     with SingleTickerProviderStateMixin {
   late TabController _tabController;
   late String _query;
-  List<String> _sortOptions = ['date_desc'];
+  List<SearchSort> _sortOptions = [SearchSort.dateDesc];
   bool _isVip = false;
 
   @override
@@ -78,7 +79,9 @@ class _SearchResultScreenState extends State<SearchResultScreen>
     try {
       final user = await currentUser();
       final isVip = user?.isPremium ?? false;
-      final nextOptions = isVip ? ['date_desc', 'popular_desc'] : ['date_desc'];
+      final nextOptions = isVip
+          ? [SearchSort.dateDesc, SearchSort.popularDesc]
+          : [SearchSort.dateDesc];
       if (!mounted) return;
 
       if (_isVip == isVip && _sortOptions.length == nextOptions.length) {
@@ -133,7 +136,7 @@ class _SearchResultScreenState extends State<SearchResultScreen>
                     MaterialButton(
                       minWidth: 50,
                       onPressed: () async {
-                        String? mode = await chooseMode(context);
+                        SearchTarget? mode = await chooseMode(context);
                         if (mode != null && mode != widget.mode) {
                           if (!context.mounted) return;
                           Navigator.of(context).pushReplacement(
@@ -166,7 +169,7 @@ class _SearchResultScreenState extends State<SearchResultScreen>
                   ? TabBar(
                       controller: _tabController,
                       tabs: _sortOptions.map((sort) {
-                        if (sort == 'date_desc') {
+                        if (sort == SearchSort.dateDesc) {
                           return Tab(text: AppLocalizations.of(context)!.latest);
                         } else {
                           return Tab(text: AppLocalizations.of(context)!.popular);
@@ -202,8 +205,8 @@ class _SearchResultScreenState extends State<SearchResultScreen>
 
 class _SearchResultTab extends StatefulWidget {
   final String query;
-  final String sort;
-  final String mode;
+  final SearchSort sort;
+  final SearchTarget mode;
 
   const _SearchResultTab({
     super.key,
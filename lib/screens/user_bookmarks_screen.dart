@@ -31,7 +31,7 @@ class _UserBookmarksScreenState extends State<UserBookmarksScreen> {
   bool _hasError = false;
   String? _nextUrl;
   final ScrollController _scrollController = ScrollController();
-  String _restrict = 'public';
+  Restrict _restrict = Restrict.public;
 
   @override
   void initState() {
@@ -114,12 +114,14 @@ class _UserBookmarksScreenState extends State<UserBookmarksScreen> {
           TextButton(
             onPressed: () {
               setState(() {
-                _restrict = _restrict == 'public' ? 'private' : 'public';
+                _restrict = _restrict == Restrict.public
+                    ? Restrict.private
+                    : Restrict.public;
               });
               _loadBookmarks();
             },
             child: Text(
-              _restrict == 'public' 
+              _restrict == Restrict.public 
                 ? AppLocalizations.of(context)!.public 
                 : AppLocalizations.of(context)!.private,
               style: TextStyle(
@@ -29,7 +29,7 @@ class _UserFollowingScreenState extends State<UserFollowingScreen> {
   bool _hasError = false;
   String? _nextUrl;
   final ScrollController _scrollController = ScrollController();
-  String _restrict = 'public';
+  Restrict _restrict = Restrict.public;
 
   @override
   void initState() {
@@ -112,12 +112,14 @@ class _UserFollowingScreenState extends State<UserFollowingScreen> {
           TextButton(
             onPressed: () {
               setState(() {
-                _restrict = _restrict == 'public' ? 'private' : 'public';
+                _restrict = _restrict == Restrict.public
+                    ? Restrict.private
+                    : Restrict.public;
               });
               _loadUsers();
             },
             child: Text(
-              _restrict == 'public' 
+              _restrict == Restrict.public 
                 ? AppLocalizations.of(context)!.public 
                 : AppLocalizations.of(context)!.private,
               style: TextStyle(
@@ -226,7 +226,7 @@ class _UserInfoScreenState extends State<UserInfoScreen> {
           );
         }
       } else {
-        await followUser(userId: widget.userSample.id, restrict: "public");
+        await followUser(userId: widget.userSample.id, restrict: Restrict.public);
         setState(() {
           _isFollowing = true;
           _isFollowLoading = false;
@@ -8,7 +8,7 @@ import '../pixirust/entities.dart';
 import '../udto.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `_execute_single_download`, `block_on`
+// These functions are ignored because they are not marked as `pub`: `_execute_single_download`, `block_on`, `download_image_to_cache`, `execute_download_task_`, `load_pixiv_image_`, `placeholder_dto`, `record_image_hash`, `similar_images`, `view_history_dto`
 
 Future<String> desktopRoot() => RustLib.instance.api.crateApiApiDesktopRoot();
 
@@ -24,6 +24,58 @@ Future<String> loadProperty({required String k}) =>
 Future<void> copyImageTo({required String srcPath, required String toDir}) =>
     RustLib.instance.api.crateApiApiCopyImageTo(srcPath: srcPath, toDir: toDir);
 
+/// 修改并保存 app-api / oauth / 图片服务的地址, 空值表示使用pixiv官方地址
+Future<void> setServerUrls({
+  required String app,
+  required String oauth,
+  required String image,
+}) => RustLib.instance.api.crateApiApiSetServerUrls(
+  app: app,
+  oauth: oauth,
+  image: image,
+);
+
+Future<ProxySettings> getProxySettings() =>
+    RustLib.instance.api.crateApiApiGetProxySettings();
+
+/// 保存代理设置并立即重建网络客户端
+Future<void> setProxySettings({required ProxySettings settings}) =>
+    RustLib.instance.api.crateApiApiSetProxySettings(settings: settings);
+
+Future<DohSettings> getDohSettings() =>
+    RustLib.instance.api.crateApiApiGetDohSettings();
+
+/// 保存 DoH 设置并立即重建网络客户端
+Future<void> setDohSettings({required DohSettings settings}) =>
+    RustLib.instance.api.crateApiApiSetDohSettings(settings: settings);
+
+/// 使用 DoH 重新解析并保存 SNI bypass 的IP, 返回新的 host -> ip
+Future<Map<String, String>> refreshBypassSniHosts() =>
+    RustLib.instance.api.crateApiApiRefreshBypassSniHosts();
+
+Future<ConnectionProfile> getConnectionProfile() =>
+    RustLib.instance.api.crateApiApiGetConnectionProfile();
+
+/// 保存连接设置(超时 / 连接池 / HTTP2 / 压缩)并立即重建网络客户端
+Future<void> setConnectionProfile({required ConnectionProfile profile}) =>
+    RustLib.instance.api.crateApiApiSetConnectionProfile(profile: profile);
+
+/// 立即探测 SNI bypass 的所有候选IP, 返回每个IP的状态
+Future<List<BypassSniIpStatus>> bypassSniDiagnostics() =>
+    RustLib.instance.api.crateApiApiBypassSniDiagnostics();
+
+/// 各host的限流状态, 用于显示下载变慢的原因
+Future<List<RateLimitState>> rateLimitStates() =>
+    RustLib.instance.api.crateApiApiRateLimitStates();
+
+/// 测试当前网络设置下到各 pixiv 服务的延迟
+Future<List<ProxyTestResult>> testProxy() =>
+    RustLib.instance.api.crateApiApiTestProxy();
+
+/// 当前使用的服务地址, 依次为 app-api / oauth / 图片
+Future<List<String>> getServerUrls() =>
+    RustLib.instance.api.crateApiApiGetServerUrls();
+
 Future<void> setInChina({required bool value}) =>
     RustLib.instance.api.crateApiApiSetInChina(value: value);
 
@@ -42,18 +94,41 @@ Future<bool> loginByCode({required UiLoginByCodeQuery query}) =>
 Future<LoginUrl> createRegisterUrl() =>
     RustLib.instance.api.crateApiApiCreateRegisterUrl();
 
+/// 请求 app-api, 网络失败时返回的过期缓存在顶层带有 "stale": true
 Future<String> requestUrl({required String params}) =>
     RustLib.instance.api.crateApiApiRequestUrl(params: params);
 
+/// 加载作品列表, 返回前按本地屏蔽规则过滤（hidden_count 为被隐藏的数量）
 Future<IllustResponse> illustFromUrl({required String url}) =>
     RustLib.instance.api.crateApiApiIllustFromUrl(url: url);
 
+/// 同 illust_from_url, 可以通过 cancel(request_id) 取消
+Future<IllustResponse> illustFromUrlCancellable({
+  required String url,
+  required String requestId,
+}) => RustLib.instance.api.crateApiApiIllustFromUrlCancellable(
+  url: url,
+  requestId: requestId,
+);
+
+/// 取消 request_id 对应的所有进行中的请求（例如离开页面时）, 返回是否有请求被取消
+Future<bool> cancel({required String requestId}) =>
+    RustLib.instance.api.crateApiApiCancel(requestId: requestId);
+
+/// 本地屏蔽规则（屏蔽标签、屏蔽用户、隐藏AI/R-18/动图）
+Future<IllustFilter> getIllustFilter() =>
+    RustLib.instance.api.crateApiApiGetIllustFilter();
+
+Future<void> setIllustFilter({required IllustFilter filter}) =>
+    RustLib.instance.api.crateApiApiSetIllustFilter(filter: filter);
+
 Future<UserPreviewsResponse> userPreviewsFromUrl({required String url}) =>
     RustLib.instance.api.crateApiApiUserPreviewsFromUrl(url: url);
 
 Future<String> illustRecommendedFirstUrl() =>
     RustLib.instance.api.crateApiApiIllustRecommendedFirstUrl();
 
+/// 热门排序只有会员可以使用, 非会员时返回错误
 Future<String> illustSearchFirstUrl({required UiIllustSearchQuery query}) =>
     RustLib.instance.api.crateApiApiIllustSearchFirstUrl(query: query);
 
@@ -63,6 +138,47 @@ Future<String> illustRankFirstUrl({required UiIllustRankQuery query}) =>
 Future<String> userIllustsFirstUrl({required PlatformInt64 userId}) =>
     RustLib.instance.api.crateApiApiUserIllustsFirstUrl(userId: userId);
 
+Future<String> userBookmarksFirstUrl({
+  required PlatformInt64 userId,
+  required Restrict restrict,
+  String? tag,
+}) => RustLib.instance.api.crateApiApiUserBookmarksFirstUrl(
+  userId: userId,
+  restrict: restrict,
+  tag: tag,
+);
+
+/// 从任意列表的第一页url创建游标, 返回句柄, 用完后调用 cursor_close
+///
+/// 游标自己保存 next_url, 并去除不同页之间重复的作品
+Future<PlatformInt64> cursorOpen({required String firstUrl}) =>
+    RustLib.instance.api.crateApiApiCursorOpen(firstUrl: firstUrl);
+
+/// 读取下一页, 没有更多时返回 None
+Future<IllustResponse?> cursorNext({required PlatformInt64 handle}) =>
+    RustLib.instance.api.crateApiApiCursorNext(handle: handle);
+
+/// 以 stream 的形式依次返回剩余的页, 最多 max_pages 页, 需要更多时再次调用
+Stream<IllustResponse> cursorStream({
+  required PlatformInt64 handle,
+  required PlatformInt64 maxPages,
+}) => RustLib.instance.api.crateApiApiCursorStream(
+  handle: handle,
+  maxPages: maxPages,
+);
+
+/// 读取剩余的作品直到没有更多或达到 limit 个, 用于导出和批量任务; 最后一页超出 limit 的作品由下一次读取返回
+Future<List<Illust>> cursorCollectAll({
+  required PlatformInt64 handle,
+  required PlatformInt64 limit,
+}) => RustLib.instance.api.crateApiApiCursorCollectAll(
+  handle: handle,
+  limit: limit,
+);
+
+Future<void> cursorClose({required PlatformInt64 handle}) =>
+    RustLib.instance.api.crateApiApiCursorClose(handle: handle);
+
 Future<IllustTrendingTags> illustTrendingTags() =>
     RustLib.instance.api.crateApiApiIllustTrendingTags();
 
@@ -73,12 +189,60 @@ Future<String> illustTrendingTagsUrl() =>
 Future<String> loadPixivImage({required String url}) =>
     RustLib.instance.api.crateApiApiLoadPixivImage(url: url);
 
+/// 同 load_pixiv_image, 可以通过 cancel(request_id) 取消
+Future<String> loadPixivImageCancellable({
+  required String url,
+  required String requestId,
+}) => RustLib.instance.api.crateApiApiLoadPixivImageCancellable(
+  url: url,
+  requestId: requestId,
+);
+
+/// 查找与指定图片相似的已缓存或已下载图片
+///
+/// path_or_url 为本地文件路径或pixiv图片url, max_distance 为允许的最大汉明距离（0-64, 通常取 5-10）
+Future<List<SimilarImageDto>> findSimilarImages({
+  required String pathOrUrl,
+  required int maxDistance,
+}) => RustLib.instance.api.crateApiApiFindSimilarImages(
+  pathOrUrl: pathOrUrl,
+  maxDistance: maxDistance,
+);
+
+/// 下载pixiv的图片并生成缩略图
+///
+/// 缩略图等比缩放到 max_w * max_h 以内（原图更小时不放大），format 为 webp 或 jpeg。
+/// 每种尺寸/格式作为单独的 network_image 记录缓存，原图同样会被缓存。
+Future<String> loadPixivImageResized({
+  required String url,
+  required int maxW,
+  required int maxH,
+  required String format,
+}) => RustLib.instance.api.crateApiApiLoadPixivImageResized(
+  url: url,
+  maxW: maxW,
+  maxH: maxH,
+  format: format,
+);
+
+/// 计算图片的占位信息（blurhash + 主色调）并保存, 图片未缓存时会先下载
+Future<ImagePlaceholderDto> loadImagePlaceholder({required String url}) =>
+    RustLib.instance.api.crateApiApiLoadImagePlaceholder(url: url);
+
+/// 读取已保存的占位信息, 不会发起网络请求（图片缓存被清除后仍可用）
+Future<ImagePlaceholderDto?> cachedImagePlaceholder({required String url}) =>
+    RustLib.instance.api.crateApiApiCachedImagePlaceholder(url: url);
+
+/// 清除图片缓存（Pixiv 图片缓存 + 索引表）
+Future<void> clearImageCache() =>
+    RustLib.instance.api.crateApiApiClearImageCache();
+
 Future<UserDetail> userDetail({required PlatformInt64 userId}) =>
     RustLib.instance.api.crateApiApiUserDetail(userId: userId);
 
 Future<void> followUser({
   required PlatformInt64 userId,
-  required String restrict,
+  required Restrict restrict,
 }) => RustLib.instance.api.crateApiApiFollowUser(
   userId: userId,
   restrict: restrict,
@@ -89,7 +253,7 @@ Future<void> unfollowUser({required PlatformInt64 userId}) =>
 
 Future<UserPreviewsResponse> userFollowing({
   required PlatformInt64 userId,
-  required String restrict,
+  required Restrict restrict,
 }) => RustLib.instance.api.crateApiApiUserFollowing(
   userId: userId,
   restrict: restrict,
@@ -97,7 +261,7 @@ Future<UserPreviewsResponse> userFollowing({
 
 Future<IllustResponse> userBookmarks({
   required PlatformInt64 userId,
-  required String restrict,
+  required Restrict restrict,
   String? tag,
 }) => RustLib.instance.api.crateApiApiUserBookmarks(
   userId: userId,
@@ -107,7 +271,7 @@ Future<IllustResponse> userBookmarks({
 
 Future<void> addBookmark({
   required PlatformInt64 illustId,
-  required String restrict,
+  required Restrict restrict,
 }) => RustLib.instance.api.crateApiApiAddBookmark(
   illustId: illustId,
   restrict: restrict,
@@ -119,6 +283,107 @@ Future<void> deleteBookmark({required PlatformInt64 illustId}) =>
 Future<UiCurrentUser?> currentUser() =>
     RustLib.instance.api.crateApiApiCurrentUser();
 
+/// 监听登录状态变化（登录、token刷新、刷新失败、退出）, 新的监听会替换旧的
+Stream<AuthEvent> authEventStream() =>
+    RustLib.instance.api.crateApiApiAuthEventStream();
+
+/// 退出登录, 之后 current_user 返回 None
+///
+/// 同时删除该账号的响应缓存, clear_history 为 true 时还会清除该账号的本地浏览历史
+Future<void> logout({required bool clearHistory}) =>
+    RustLib.instance.api.crateApiApiLogout(clearHistory: clearHistory);
+
+/// 所有已登录的账号, 登录新账号时会自动加入并切换
+Future<List<UiAccount>> listAccounts() =>
+    RustLib.instance.api.crateApiApiListAccounts();
+
+/// 切换当前账号, 切换完成前开始的请求仍使用旧账号
+Future<void> switchAccount({required PlatformInt64 userId}) =>
+    RustLib.instance.api.crateApiApiSwitchAccount(userId: userId);
+
+Future<void> removeAccount({required PlatformInt64 userId}) =>
+    RustLib.instance.api.crateApiApiRemoveAccount(userId: userId);
+
+/// 记录一次浏览（本地保存, 不依赖pixiv会员的浏览历史）
+Future<void> recordView({required Illust illust}) =>
+    RustLib.instance.api.crateApiApiRecordView(illust: illust);
+
+/// 当前账号的浏览历史, 按最后浏览时间倒序分页, page 从 0 开始
+Future<List<ViewHistoryDto>> listHistory({
+  required PlatformInt64 page,
+  required PlatformInt64 pageSize,
+}) => RustLib.instance.api.crateApiApiListHistory(
+  page: page,
+  pageSize: pageSize,
+);
+
+/// 按作品id、标题、作者名或标签搜索当前账号的浏览历史
+Future<List<ViewHistoryDto>> searchHistory({required String keyword}) =>
+    RustLib.instance.api.crateApiApiSearchHistory(keyword: keyword);
+
+/// 清空 app-api 的响应缓存
+Future<void> clearResponseCache() =>
+    RustLib.instance.api.crateApiApiClearResponseCache();
+
+/// 网络失败时是否使用过期的响应缓存, 只对有缓存时间的接口(排行榜、推荐、用户详情、热门标签)生效
+Future<bool> getResponseCacheOffline() =>
+    RustLib.instance.api.crateApiApiGetResponseCacheOffline();
+
+Future<void> setResponseCacheOffline({required bool value}) =>
+    RustLib.instance.api.crateApiApiSetResponseCacheOffline(value: value);
+
+/// 清除当前账号的浏览历史
+Future<void> clearHistory() => RustLib.instance.api.crateApiApiClearHistory();
+
+/// 最新一期已公布的排行榜日期(yyyy-MM-dd)
+Future<String> latestRankingAvailableDate() =>
+    RustLib.instance.api.crateApiApiLatestRankingAvailableDate();
+
+/// 每小时自动记录的排行榜
+Future<List<RankMode>> getRankingCaptureModes() =>
+    RustLib.instance.api.crateApiApiGetRankingCaptureModes();
+
+Future<void> setRankingCaptureModes({required List<RankMode> modes}) =>
+    RustLib.instance.api.crateApiApiSetRankingCaptureModes(modes: modes);
+
+/// 立即记录某一天的排行榜, date 为空时使用最新一期, 返回记录的作品数量
+Future<PlatformInt64> captureRanking({
+  required RankMode mode,
+  required String date,
+}) => RustLib.instance.api.crateApiApiCaptureRanking(mode: mode, date: date);
+
+/// 已记录的日期, 新的在前
+Future<List<String>> rankingSnapshotDates({required RankMode mode}) =>
+    RustLib.instance.api.crateApiApiRankingSnapshotDates(mode: mode);
+
+Future<List<RankingEntryDto>> rankingSnapshot({
+  required RankMode mode,
+  required String date,
+}) => RustLib.instance.api.crateApiApiRankingSnapshot(mode: mode, date: date);
+
+/// 某个作者的作品在排行榜中的历史名次
+Future<List<RankingEntryDto>> rankingHistoryOfUser({
+  required RankMode mode,
+  required PlatformInt64 userId,
+}) => RustLib.instance.api.crateApiApiRankingHistoryOfUser(
+  mode: mode,
+  userId: userId,
+);
+
+/// 比较两天的排行榜: 新上榜、上升、下降、落榜
+Future<RankingDiff> rankingDiff({
+  required RankMode mode,
+  required String fromDate,
+  required String toDate,
+}) => RustLib.instance.api.crateApiApiRankingDiff(
+  mode: mode,
+  fromDate: fromDate,
+  toDate: toDate,
+);
+
+Future<void> clearRankingSnapshots() =>
+    RustLib.instance.api.crateApiApiClearRankingSnapshots();
+
 Future<PlatformInt64> createDownloadTask({
   required PlatformInt64 illustId,
   required String illustTitle,
@@ -166,3 +431,24 @@ Future<void> deleteCompletedDownloadTasks() =>
 
 Future<void> executeDownloadTask({required PlatformInt64 id}) =>
     RustLib.instance.api.crateApiApiExecuteDownloadTask(id: id);
+
+/// 同 execute_download_task, 可以通过 cancel(request_id) 取消, 取消的任务标记为失败但不计入重试次数
+Future<void> executeDownloadTaskCancellable({
+  required PlatformInt64 id,
+  required String requestId,
+}) => RustLib.instance.api.crateApiApiExecuteDownloadTaskCancellable(
+  id: id,
+  requestId: requestId,
+);
+
+/// Find previously downloaded images that look like this task's image.
+///
+/// Call after `execute_download_task` and before saving to the target, so the
+/// UI can warn about near-duplicates (re-uploads, crops, re-encodes).
+Future<List<SimilarImageDto>> findDownloadDuplicates({
+  required PlatformInt64 id,
+  required int maxDistance,
+}) => RustLib.instance.api.crateApiApiFindDownloadDuplicates(
+  id: id,
+  maxDistance: maxDistance,
+);
@@ -69,7 +69,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 1265287069;
+  int get rustContentHash => 1013427541;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -82,9 +82,32 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 abstract class RustLibApi extends BaseApi {
   Future<void> crateApiApiAddBookmark({
     required PlatformInt64 illustId,
-    required String restrict,
+    required Restrict restrict,
   });
 
+  Stream<AuthEvent> crateApiApiAuthEventStream();
+
+  Future<List<BypassSniIpStatus>> crateApiApiBypassSniDiagnostics();
+
+  Future<ImagePlaceholderDto?> crateApiApiCachedImagePlaceholder({
+    required String url,
+  });
+
+  Future<bool> crateApiApiCancel({required String requestId});
+
+  Future<PlatformInt64> crateApiApiCaptureRanking({
+    required RankMode mode,
+    required String date,
+  });
+
+  Future<void> crateApiApiClearHistory();
+
+  Future<void> crateApiApiClearImageCache();
+
+  Future<void> crateApiApiClearRankingSnapshots();
+
+  Future<void> crateApiApiClearResponseCache();
+
   Future<void> crateApiApiCopyImageTo({
     required String srcPath,
     required String toDir,
@@ -106,6 +129,24 @@ abstract class RustLibApi extends BaseApi {
 
   Future<UiCurrentUser?> crateApiApiCurrentUser();
 
+  Future<void> crateApiApiCursorClose({required PlatformInt64 handle});
+
+  Future<List<Illust>> crateApiApiCursorCollectAll({
+    required PlatformInt64 handle,
+    required PlatformInt64 limit,
+  });
+
+  Future<IllustResponse?> crateApiApiCursorNext({
+    required PlatformInt64 handle,
+  });
+
+  Future<PlatformInt64> crateApiApiCursorOpen({required String firstUrl});
+
+  Stream<IllustResponse> crateApiApiCursorStream({
+    required PlatformInt64 handle,
+    required PlatformInt64 maxPages,
+  });
+
   Future<void> crateApiApiDeleteBookmark({required PlatformInt64 illustId});
 
   Future<void> crateApiApiDeleteCompletedDownloadTasks();
@@ -116,21 +157,55 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiApiExecuteDownloadTask({required PlatformInt64 id});
 
+  Future<void> crateApiApiExecuteDownloadTaskCancellable({
+    required PlatformInt64 id,
+    required String requestId,
+  });
+
+  Future<List<SimilarImageDto>> crateApiApiFindDownloadDuplicates({
+    required PlatformInt64 id,
+    required int maxDistance,
+  });
+
+  Future<List<SimilarImageDto>> crateApiApiFindSimilarImages({
+    required String pathOrUrl,
+    required int maxDistance,
+  });
+
   Future<void> crateApiApiFollowUser({
     required PlatformInt64 userId,
-    required String restrict,
+    required Restrict restrict,
   });
 
   Future<List<DownloadTaskDto>> crateApiApiGetAllDownloadTasks();
 
+  Future<ConnectionProfile> crateApiApiGetConnectionProfile();
+
+  Future<DohSettings> crateApiApiGetDohSettings();
+
+  Future<IllustFilter> crateApiApiGetIllustFilter();
+
   Future<bool> crateApiApiGetInChina();
 
   Future<List<DownloadTaskDto>> crateApiApiGetPendingDownloadTasks();
 
+  Future<ProxySettings> crateApiApiGetProxySettings();
+
+  Future<List<RankMode>> crateApiApiGetRankingCaptureModes();
+
+  Future<bool> crateApiApiGetResponseCacheOffline();
+
+  Future<List<String>> crateApiApiGetServerUrls();
+
   String crateApiSimpleGreet({required String name});
 
   Future<IllustResponse> crateApiApiIllustFromUrl({required String url});
 
+  Future<IllustResponse> crateApiApiIllustFromUrlCancellable({
+    required String url,
+    required String requestId,
+  });
+
   Future<String> crateApiApiIllustRankFirstUrl({
     required UiIllustRankQuery query,
   });
@@ -149,24 +224,109 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiSimpleInitApp();
 
+  Future<String> crateApiApiLatestRankingAvailableDate();
+
+  Future<List<UiAccount>> crateApiApiListAccounts();
+
+  Future<List<ViewHistoryDto>> crateApiApiListHistory({
+    required PlatformInt64 page,
+    required PlatformInt64 pageSize,
+  });
+
+  Future<ImagePlaceholderDto> crateApiApiLoadImagePlaceholder({
+    required String url,
+  });
+
   Future<String> crateApiApiLoadPixivImage({required String url});
 
+  Future<String> crateApiApiLoadPixivImageCancellable({
+    required String url,
+    required String requestId,
+  });
+
+  Future<String> crateApiApiLoadPixivImageResized({
+    required String url,
+    required int maxW,
+    required int maxH,
+    required String format,
+  });
+
   Future<String> crateApiApiLoadProperty({required String k});
 
   Future<bool> crateApiApiLoginByCode({required UiLoginByCodeQuery query});
 
+  Future<void> crateApiApiLogout({required bool clearHistory});
+
   Future<void> crateApiApiPerInChina();
 
   Future<bool> crateApiApiPreLogin();
 
+  Future<RankingDiff> crateApiApiRankingDiff({
+    required RankMode mode,
+    required String fromDate,
+    required String toDate,
+  });
+
+  Future<List<RankingEntryDto>> crateApiApiRankingHistoryOfUser({
+    required RankMode mode,
+    required PlatformInt64 userId,
+  });
+
+  Future<List<RankingEntryDto>> crateApiApiRankingSnapshot({
+    required RankMode mode,
+    required String date,
+  });
+
+  Future<List<String>> crateApiApiRankingSnapshotDates({
+    required RankMode mode,
+  });
+
+  Future<List<RateLimitState>> crateApiApiRateLimitStates();
+
+  Future<void> crateApiApiRecordView({required Illust illust});
+
+  Future<Map<String, String>> crateApiApiRefreshBypassSniHosts();
+
+  Future<void> crateApiApiRemoveAccount({required PlatformInt64 userId});
+
   Future<String> crateApiApiRequestUrl({required String params});
 
   Future<void> crateApiApiRetryDownloadTask({required PlatformInt64 id});
 
   Future<void> crateApiApiSaveProperty({required String k, required String v});
 
+  Future<List<ViewHistoryDto>> crateApiApiSearchHistory({
+    required String keyword,
+  });
+
+  Future<void> crateApiApiSetConnectionProfile({
+    required ConnectionProfile profile,
+  });
+
+  Future<void> crateApiApiSetDohSettings({required DohSettings settings});
+
+  Future<void> crateApiApiSetIllustFilter({required IllustFilter filter});
+
   Future<void> crateApiApiSetInChina({required bool value});
 
+  Future<void> crateApiApiSetProxySettings({required ProxySettings settings});
+
+  Future<void> crateApiApiSetRankingCaptureModes({
+    required List<RankMode> modes,
+  });
+
+  Future<void> crateApiApiSetResponseCacheOffline({required bool value});
+
+  Future<void> crateApiApiSetServerUrls({
+    required String app,
+    required String oauth,
+    required String image,
+  });
+
+  Future<void> crateApiApiSwitchAccount({required PlatformInt64 userId});
+
+  Future<List<ProxyTestResult>> crateApiApiTestProxy();
+
   Future<void> crateApiApiUnfollowUser({required PlatformInt64 userId});
 
   Future<void> crateApiApiUpdateDownloadTaskStatus({
@@ -178,7 +338,13 @@ abstract class RustLibApi extends BaseApi {
 
   Future<IllustResponse> crateApiApiUserBookmarks({
     required PlatformInt64 userId,
-    required String restrict,
+    required Restrict restrict,
+    String? tag,
+  });
+
+  Future<String> crateApiApiUserBookmarksFirstUrl({
+    required PlatformInt64 userId,
+    required Restrict restrict,
     String? tag,
   });
 
@@ -186,7 +352,7 @@ abstract class RustLibApi extends BaseApi {
 
   Future<UserPreviewsResponse> crateApiApiUserFollowing({
     required PlatformInt64 userId,
-    required String restrict,
+    required Restrict restrict,
   });
 
   Future<String> crateApiApiUserIllustsFirstUrl({
@@ -209,14 +375,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @override
   Future<void> crateApiApiAddBookmark({
     required PlatformInt64 illustId,
-    required String restrict,
+    required Restrict restrict,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_i_64(illustId, serializer);
-          sse_encode_String(restrict, serializer);
+          sse_encode_restrict(restrict, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -241,60 +407,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   );
 
   @override
-  Future<void> crateApiApiCopyImageTo({
-    required String srcPath,
-    required String toDir,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(srcPath, serializer);
-          sse_encode_String(toDir, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 2,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+  Stream<AuthEvent> crateApiApiAuthEventStream() {
+    final sink = RustStreamSink<AuthEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_auth_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 2,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiApiAuthEventStreamConstMeta,
+          argValues: [sink],
+          apiImpl: this,
         ),
-        constMeta: kCrateApiApiCopyImageToConstMeta,
-        argValues: [srcPath, toDir],
-        apiImpl: this,
       ),
     );
+    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiApiCopyImageToConstMeta => const TaskConstMeta(
-    debugName: "copy_image_to",
-    argNames: ["srcPath", "toDir"],
-  );
+  TaskConstMeta get kCrateApiApiAuthEventStreamConstMeta =>
+      const TaskConstMeta(debugName: "auth_event_stream", argNames: ["sink"]);
 
   @override
-  Future<PlatformInt64> crateApiApiCreateDownloadTask({
-    required PlatformInt64 illustId,
-    required String illustTitle,
-    required int pageIndex,
-    required int pageCount,
-    required String url,
-    required String targetPath,
-    required String saveTarget,
-  }) {
+  Future<List<BypassSniIpStatus>> crateApiApiBypassSniDiagnostics() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(illustId, serializer);
-          sse_encode_String(illustTitle, serializer);
-          sse_encode_i_32(pageIndex, serializer);
-          sse_encode_i_32(pageCount, serializer);
-          sse_encode_String(url, serializer);
-          sse_encode_String(targetPath, serializer);
-          sse_encode_String(saveTarget, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -303,44 +452,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_i_64,
+          decodeSuccessData: sse_decode_list_bypass_sni_ip_status,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiCreateDownloadTaskConstMeta,
-        argValues: [
-          illustId,
-          illustTitle,
-          pageIndex,
-          pageCount,
-          url,
-          targetPath,
-          saveTarget,
-        ],
+        constMeta: kCrateApiApiBypassSniDiagnosticsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiCreateDownloadTaskConstMeta =>
-      const TaskConstMeta(
-        debugName: "create_download_task",
-        argNames: [
-          "illustId",
-          "illustTitle",
-          "pageIndex",
-          "pageCount",
-          "url",
-          "targetPath",
-          "saveTarget",
-        ],
-      );
+  TaskConstMeta get kCrateApiApiBypassSniDiagnosticsConstMeta =>
+      const TaskConstMeta(debugName: "bypass_sni_diagnostics", argNames: []);
 
   @override
-  Future<LoginUrl> crateApiApiCreateLoginUrl() {
+  Future<ImagePlaceholderDto?> crateApiApiCachedImagePlaceholder({
+    required String url,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -349,25 +482,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_login_url,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_opt_box_autoadd_image_placeholder_dto,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiCreateLoginUrlConstMeta,
-        argValues: [],
+        constMeta: kCrateApiApiCachedImagePlaceholderConstMeta,
+        argValues: [url],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiCreateLoginUrlConstMeta =>
-      const TaskConstMeta(debugName: "create_login_url", argNames: []);
+  TaskConstMeta get kCrateApiApiCachedImagePlaceholderConstMeta =>
+      const TaskConstMeta(
+        debugName: "cached_image_placeholder",
+        argNames: ["url"],
+      );
 
   @override
-  Future<LoginUrl> crateApiApiCreateRegisterUrl() {
+  Future<bool> crateApiApiCancel({required String requestId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(requestId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -376,25 +513,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_login_url,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiApiCreateRegisterUrlConstMeta,
-        argValues: [],
+        constMeta: kCrateApiApiCancelConstMeta,
+        argValues: [requestId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiCreateRegisterUrlConstMeta =>
-      const TaskConstMeta(debugName: "create_register_url", argNames: []);
+  TaskConstMeta get kCrateApiApiCancelConstMeta =>
+      const TaskConstMeta(debugName: "cancel", argNames: ["requestId"]);
 
   @override
-  Future<UiCurrentUser?> crateApiApiCurrentUser() {
+  Future<PlatformInt64> crateApiApiCaptureRanking({
+    required RankMode mode,
+    required String date,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_rank_mode(mode, serializer);
+          sse_encode_String(date, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -403,26 +545,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_ui_current_user,
+          decodeSuccessData: sse_decode_i_64,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiCurrentUserConstMeta,
-        argValues: [],
+        constMeta: kCrateApiApiCaptureRankingConstMeta,
+        argValues: [mode, date],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiCurrentUserConstMeta =>
-      const TaskConstMeta(debugName: "current_user", argNames: []);
+  TaskConstMeta get kCrateApiApiCaptureRankingConstMeta => const TaskConstMeta(
+    debugName: "capture_ranking",
+    argNames: ["mode", "date"],
+  );
 
   @override
-  Future<void> crateApiApiDeleteBookmark({required PlatformInt64 illustId}) {
+  Future<void> crateApiApiClearHistory() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(illustId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -434,18 +577,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiDeleteBookmarkConstMeta,
-        argValues: [illustId],
+        constMeta: kCrateApiApiClearHistoryConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiDeleteBookmarkConstMeta =>
-      const TaskConstMeta(debugName: "delete_bookmark", argNames: ["illustId"]);
+  TaskConstMeta get kCrateApiApiClearHistoryConstMeta =>
+      const TaskConstMeta(debugName: "clear_history", argNames: []);
 
   @override
-  Future<void> crateApiApiDeleteCompletedDownloadTasks() {
+  Future<void> crateApiApiClearImageCache() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -461,26 +604,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiDeleteCompletedDownloadTasksConstMeta,
+        constMeta: kCrateApiApiClearImageCacheConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiDeleteCompletedDownloadTasksConstMeta =>
-      const TaskConstMeta(
-        debugName: "delete_completed_download_tasks",
-        argNames: [],
-      );
+  TaskConstMeta get kCrateApiApiClearImageCacheConstMeta =>
+      const TaskConstMeta(debugName: "clear_image_cache", argNames: []);
 
   @override
-  Future<void> crateApiApiDeleteDownloadTask({required PlatformInt64 id}) {
+  Future<void> crateApiApiClearRankingSnapshots() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(id, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -492,18 +631,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiDeleteDownloadTaskConstMeta,
-        argValues: [id],
+        constMeta: kCrateApiApiClearRankingSnapshotsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiDeleteDownloadTaskConstMeta =>
-      const TaskConstMeta(debugName: "delete_download_task", argNames: ["id"]);
+  TaskConstMeta get kCrateApiApiClearRankingSnapshotsConstMeta =>
+      const TaskConstMeta(debugName: "clear_ranking_snapshots", argNames: []);
 
   @override
-  Future<String> crateApiApiDesktopRoot() {
+  Future<void> crateApiApiClearResponseCache() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -516,26 +655,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiDesktopRootConstMeta,
+        constMeta: kCrateApiApiClearResponseCacheConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiDesktopRootConstMeta =>
-      const TaskConstMeta(debugName: "desktop_root", argNames: []);
+  TaskConstMeta get kCrateApiApiClearResponseCacheConstMeta =>
+      const TaskConstMeta(debugName: "clear_response_cache", argNames: []);
 
   @override
-  Future<void> crateApiApiExecuteDownloadTask({required PlatformInt64 id}) {
+  Future<void> crateApiApiCopyImageTo({
+    required String srcPath,
+    required String toDir,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(id, serializer);
+          sse_encode_String(srcPath, serializer);
+          sse_encode_String(toDir, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -547,27 +690,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiExecuteDownloadTaskConstMeta,
-        argValues: [id],
+        constMeta: kCrateApiApiCopyImageToConstMeta,
+        argValues: [srcPath, toDir],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiExecuteDownloadTaskConstMeta =>
-      const TaskConstMeta(debugName: "execute_download_task", argNames: ["id"]);
+  TaskConstMeta get kCrateApiApiCopyImageToConstMeta => const TaskConstMeta(
+    debugName: "copy_image_to",
+    argNames: ["srcPath", "toDir"],
+  );
 
   @override
-  Future<void> crateApiApiFollowUser({
-    required PlatformInt64 userId,
-    required String restrict,
+  Future<PlatformInt64> crateApiApiCreateDownloadTask({
+    required PlatformInt64 illustId,
+    required String illustTitle,
+    required int pageIndex,
+    required int pageCount,
+    required String url,
+    required String targetPath,
+    required String saveTarget,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(userId, serializer);
-          sse_encode_String(restrict, serializer);
+          sse_encode_i_64(illustId, serializer);
+          sse_encode_String(illustTitle, serializer);
+          sse_encode_i_32(pageIndex, serializer);
+          sse_encode_i_32(pageCount, serializer);
+          sse_encode_String(url, serializer);
+          sse_encode_String(targetPath, serializer);
+          sse_encode_String(saveTarget, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -576,23 +731,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_i_64,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiFollowUserConstMeta,
-        argValues: [userId, restrict],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiApiFollowUserConstMeta => const TaskConstMeta(
-    debugName: "follow_user",
-    argNames: ["userId", "restrict"],
-  );
+        constMeta: kCrateApiApiCreateDownloadTaskConstMeta,
+        argValues: [
+          illustId,
+          illustTitle,
+          pageIndex,
+          pageCount,
+          url,
+          targetPath,
+          saveTarget,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiCreateDownloadTaskConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_download_task",
+        argNames: [
+          "illustId",
+          "illustTitle",
+          "pageIndex",
+          "pageCount",
+          "url",
+          "targetPath",
+          "saveTarget",
+        ],
+      );
 
   @override
-  Future<List<DownloadTaskDto>> crateApiApiGetAllDownloadTasks() {
+  Future<LoginUrl> crateApiApiCreateLoginUrl() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -605,21 +777,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_download_task_dto,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_login_url,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiApiGetAllDownloadTasksConstMeta,
+        constMeta: kCrateApiApiCreateLoginUrlConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiGetAllDownloadTasksConstMeta =>
-      const TaskConstMeta(debugName: "get_all_download_tasks", argNames: []);
+  TaskConstMeta get kCrateApiApiCreateLoginUrlConstMeta =>
+      const TaskConstMeta(debugName: "create_login_url", argNames: []);
 
   @override
-  Future<bool> crateApiApiGetInChina() {
+  Future<LoginUrl> crateApiApiCreateRegisterUrl() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -632,21 +804,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_login_url,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiGetInChinaConstMeta,
+        constMeta: kCrateApiApiCreateRegisterUrlConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiGetInChinaConstMeta =>
-      const TaskConstMeta(debugName: "get_in_china", argNames: []);
+  TaskConstMeta get kCrateApiApiCreateRegisterUrlConstMeta =>
+      const TaskConstMeta(debugName: "create_register_url", argNames: []);
 
   @override
-  Future<List<DownloadTaskDto>> crateApiApiGetPendingDownloadTasks() {
+  Future<UiCurrentUser?> crateApiApiCurrentUser() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -659,149 +831,1649 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_download_task_dto,
+          decodeSuccessData: sse_decode_opt_box_autoadd_ui_current_user,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiGetPendingDownloadTasksConstMeta,
+        constMeta: kCrateApiApiCurrentUserConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiGetPendingDownloadTasksConstMeta =>
+  TaskConstMeta get kCrateApiApiCurrentUserConstMeta =>
+      const TaskConstMeta(debugName: "current_user", argNames: []);
+
+  @override
+  Future<void> crateApiApiCursorClose({required PlatformInt64 handle}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(handle, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiCursorCloseConstMeta,
+        argValues: [handle],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiCursorCloseConstMeta =>
+      const TaskConstMeta(debugName: "cursor_close", argNames: ["handle"]);
+
+  @override
+  Future<List<Illust>> crateApiApiCursorCollectAll({
+    required PlatformInt64 handle,
+    required PlatformInt64 limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(handle, serializer);
+          sse_encode_i_64(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_illust,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiCursorCollectAllConstMeta,
+        argValues: [handle, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiCursorCollectAllConstMeta =>
       const TaskConstMeta(
-        debugName: "get_pending_download_tasks",
+        debugName: "cursor_collect_all",
+        argNames: ["handle", "limit"],
+      );
+
+  @override
+  Future<IllustResponse?> crateApiApiCursorNext({
+    required PlatformInt64 handle,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(handle, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_illust_response,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiCursorNextConstMeta,
+        argValues: [handle],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiCursorNextConstMeta =>
+      const TaskConstMeta(debugName: "cursor_next", argNames: ["handle"]);
+
+  @override
+  Future<PlatformInt64> crateApiApiCursorOpen({required String firstUrl}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(firstUrl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiCursorOpenConstMeta,
+        argValues: [firstUrl],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiCursorOpenConstMeta =>
+      const TaskConstMeta(debugName: "cursor_open", argNames: ["firstUrl"]);
+
+  @override
+  Stream<IllustResponse> crateApiApiCursorStream({
+    required PlatformInt64 handle,
+    required PlatformInt64 maxPages,
+  }) {
+    final sink = RustStreamSink<IllustResponse>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_i_64(handle, serializer);
+            sse_encode_i_64(maxPages, serializer);
+            sse_encode_StreamSink_illust_response_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 20,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiApiCursorStreamConstMeta,
+          argValues: [handle, maxPages, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiApiCursorStreamConstMeta => const TaskConstMeta(
+    debugName: "cursor_stream",
+    argNames: ["handle", "maxPages", "sink"],
+  );
+
+  @override
+  Future<void> crateApiApiDeleteBookmark({required PlatformInt64 illustId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(illustId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiDeleteBookmarkConstMeta,
+        argValues: [illustId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiDeleteBookmarkConstMeta =>
+      const TaskConstMeta(debugName: "delete_bookmark", argNames: ["illustId"]);
+
+  @override
+  Future<void> crateApiApiDeleteCompletedDownloadTasks() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiDeleteCompletedDownloadTasksConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiDeleteCompletedDownloadTasksConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_completed_download_tasks",
         argNames: [],
       );
 
   @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<void> crateApiApiDeleteDownloadTask({required PlatformInt64 id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiDeleteDownloadTaskConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiDeleteDownloadTaskConstMeta =>
+      const TaskConstMeta(debugName: "delete_download_task", argNames: ["id"]);
+
+  @override
+  Future<String> crateApiApiDesktopRoot() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiDesktopRootConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiDesktopRootConstMeta =>
+      const TaskConstMeta(debugName: "desktop_root", argNames: []);
+
+  @override
+  Future<void> crateApiApiExecuteDownloadTask({required PlatformInt64 id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiExecuteDownloadTaskConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiExecuteDownloadTaskConstMeta =>
+      const TaskConstMeta(debugName: "execute_download_task", argNames: ["id"]);
+
+  @override
+  Future<void> crateApiApiExecuteDownloadTaskCancellable({
+    required PlatformInt64 id,
+    required String requestId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(id, serializer);
+          sse_encode_String(requestId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiExecuteDownloadTaskCancellableConstMeta,
+        argValues: [id, requestId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiExecuteDownloadTaskCancellableConstMeta =>
+      const TaskConstMeta(
+        debugName: "execute_download_task_cancellable",
+        argNames: ["id", "requestId"],
+      );
+
+  @override
+  Future<List<SimilarImageDto>> crateApiApiFindDownloadDuplicates({
+    required PlatformInt64 id,
+    required int maxDistance,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(id, serializer);
+          sse_encode_u_32(maxDistance, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_similar_image_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiFindDownloadDuplicatesConstMeta,
+        argValues: [id, maxDistance],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiFindDownloadDuplicatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "find_download_duplicates",
+        argNames: ["id", "maxDistance"],
+      );
+
+  @override
+  Future<List<SimilarImageDto>> crateApiApiFindSimilarImages({
+    required String pathOrUrl,
+    required int maxDistance,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pathOrUrl, serializer);
+          sse_encode_u_32(maxDistance, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_similar_image_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiFindSimilarImagesConstMeta,
+        argValues: [pathOrUrl, maxDistance],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiFindSimilarImagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "find_similar_images",
+        argNames: ["pathOrUrl", "maxDistance"],
+      );
+
+  @override
+  Future<void> crateApiApiFollowUser({
+    required PlatformInt64 userId,
+    required Restrict restrict,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(userId, serializer);
+          sse_encode_restrict(restrict, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiFollowUserConstMeta,
+        argValues: [userId, restrict],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiFollowUserConstMeta => const TaskConstMeta(
+    debugName: "follow_user",
+    argNames: ["userId", "restrict"],
+  );
+
+  @override
+  Future<List<DownloadTaskDto>> crateApiApiGetAllDownloadTasks() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_download_task_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiGetAllDownloadTasksConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiGetAllDownloadTasksConstMeta =>
+      const TaskConstMeta(debugName: "get_all_download_tasks", argNames: []);
+
+  @override
+  Future<ConnectionProfile> crateApiApiGetConnectionProfile() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_connection_profile,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiGetConnectionProfileConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiGetConnectionProfileConstMeta =>
+      const TaskConstMeta(debugName: "get_connection_profile", argNames: []);
+
+  @override
+  Future<DohSettings> crateApiApiGetDohSettings() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_doh_settings,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiGetDohSettingsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiGetDohSettingsConstMeta =>
+      const TaskConstMeta(debugName: "get_doh_settings", argNames: []);
+
+  @override
+  Future<IllustFilter> crateApiApiGetIllustFilter() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_illust_filter,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiGetIllustFilterConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiGetIllustFilterConstMeta =>
+      const TaskConstMeta(debugName: "get_illust_filter", argNames: []);
+
+  @override
+  Future<bool> crateApiApiGetInChina() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiGetInChinaConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiGetInChinaConstMeta =>
+      const TaskConstMeta(debugName: "get_in_china", argNames: []);
+
+  @override
+  Future<List<DownloadTaskDto>> crateApiApiGetPendingDownloadTasks() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_download_task_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiGetPendingDownloadTasksConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiGetPendingDownloadTasksConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_pending_download_tasks",
+        argNames: [],
+      );
+
+  @override
+  Future<ProxySettings> crateApiApiGetProxySettings() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_proxy_settings,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiGetProxySettingsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiGetProxySettingsConstMeta =>
+      const TaskConstMeta(debugName: "get_proxy_settings", argNames: []);
+
+  @override
+  Future<List<RankMode>> crateApiApiGetRankingCaptureModes() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_rank_mode,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiGetRankingCaptureModesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiGetRankingCaptureModesConstMeta =>
+      const TaskConstMeta(debugName: "get_ranking_capture_modes", argNames: []);
+
+  @override
+  Future<bool> crateApiApiGetResponseCacheOffline() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiGetResponseCacheOfflineConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiGetResponseCacheOfflineConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_response_cache_offline",
+        argNames: [],
+      );
+
+  @override
+  Future<List<String>> crateApiApiGetServerUrls() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiGetServerUrlsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiGetServerUrlsConstMeta =>
+      const TaskConstMeta(debugName: "get_server_urls", argNames: []);
+
+  @override
+  String crateApiSimpleGreet({required String name}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGreetConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
+      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+
+  @override
+  Future<IllustResponse> crateApiApiIllustFromUrl({required String url}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_illust_response,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiIllustFromUrlConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiIllustFromUrlConstMeta =>
+      const TaskConstMeta(debugName: "illust_from_url", argNames: ["url"]);
+
+  @override
+  Future<IllustResponse> crateApiApiIllustFromUrlCancellable({
+    required String url,
+    required String requestId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          sse_encode_String(requestId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_illust_response,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiIllustFromUrlCancellableConstMeta,
+        argValues: [url, requestId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiIllustFromUrlCancellableConstMeta =>
+      const TaskConstMeta(
+        debugName: "illust_from_url_cancellable",
+        argNames: ["url", "requestId"],
+      );
+
+  @override
+  Future<String> crateApiApiIllustRankFirstUrl({
+    required UiIllustRankQuery query,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_ui_illust_rank_query(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiIllustRankFirstUrlConstMeta,
+        argValues: [query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiIllustRankFirstUrlConstMeta =>
+      const TaskConstMeta(
+        debugName: "illust_rank_first_url",
+        argNames: ["query"],
+      );
+
+  @override
+  Future<String> crateApiApiIllustRecommendedFirstUrl() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiIllustRecommendedFirstUrlConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiIllustRecommendedFirstUrlConstMeta =>
+      const TaskConstMeta(
+        debugName: "illust_recommended_first_url",
+        argNames: [],
+      );
+
+  @override
+  Future<String> crateApiApiIllustSearchFirstUrl({
+    required UiIllustSearchQuery query,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_ui_illust_search_query(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiIllustSearchFirstUrlConstMeta,
+        argValues: [query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiIllustSearchFirstUrlConstMeta =>
+      const TaskConstMeta(
+        debugName: "illust_search_first_url",
+        argNames: ["query"],
+      );
+
+  @override
+  Future<IllustTrendingTags> crateApiApiIllustTrendingTags() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_illust_trending_tags,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiIllustTrendingTagsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiIllustTrendingTagsConstMeta =>
+      const TaskConstMeta(debugName: "illust_trending_tags", argNames: []);
+
+  @override
+  Future<String> crateApiApiIllustTrendingTagsUrl() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiIllustTrendingTagsUrlConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiIllustTrendingTagsUrlConstMeta =>
+      const TaskConstMeta(debugName: "illust_trending_tags_url", argNames: []);
+
+  @override
+  Future<void> crateApiApiInit({required String root}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(root, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiInitConstMeta,
+        argValues: [root],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiInitConstMeta =>
+      const TaskConstMeta(debugName: "init", argNames: ["root"]);
+
+  @override
+  Future<void> crateApiSimpleInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<String> crateApiApiLatestRankingAvailableDate() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiLatestRankingAvailableDateConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiLatestRankingAvailableDateConstMeta =>
+      const TaskConstMeta(
+        debugName: "latest_ranking_available_date",
+        argNames: [],
+      );
+
+  @override
+  Future<List<UiAccount>> crateApiApiListAccounts() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_ui_account,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiListAccountsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiListAccountsConstMeta =>
+      const TaskConstMeta(debugName: "list_accounts", argNames: []);
+
+  @override
+  Future<List<ViewHistoryDto>> crateApiApiListHistory({
+    required PlatformInt64 page,
+    required PlatformInt64 pageSize,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(page, serializer);
+          sse_encode_i_64(pageSize, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_view_history_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiListHistoryConstMeta,
+        argValues: [page, pageSize],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiListHistoryConstMeta => const TaskConstMeta(
+    debugName: "list_history",
+    argNames: ["page", "pageSize"],
+  );
+
+  @override
+  Future<ImagePlaceholderDto> crateApiApiLoadImagePlaceholder({
+    required String url,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_image_placeholder_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiLoadImagePlaceholderConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiLoadImagePlaceholderConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_image_placeholder",
+        argNames: ["url"],
+      );
+
+  @override
+  Future<String> crateApiApiLoadPixivImage({required String url}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiLoadPixivImageConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiLoadPixivImageConstMeta =>
+      const TaskConstMeta(debugName: "load_pixiv_image", argNames: ["url"]);
+
+  @override
+  Future<String> crateApiApiLoadPixivImageCancellable({
+    required String url,
+    required String requestId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          sse_encode_String(requestId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiLoadPixivImageCancellableConstMeta,
+        argValues: [url, requestId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiLoadPixivImageCancellableConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_pixiv_image_cancellable",
+        argNames: ["url", "requestId"],
+      );
+
+  @override
+  Future<String> crateApiApiLoadPixivImageResized({
+    required String url,
+    required int maxW,
+    required int maxH,
+    required String format,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          sse_encode_u_32(maxW, serializer);
+          sse_encode_u_32(maxH, serializer);
+          sse_encode_String(format, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiLoadPixivImageResizedConstMeta,
+        argValues: [url, maxW, maxH, format],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiLoadPixivImageResizedConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_pixiv_image_resized",
+        argNames: ["url", "maxW", "maxH", "format"],
+      );
+
+  @override
+  Future<String> crateApiApiLoadProperty({required String k}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(k, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiLoadPropertyConstMeta,
+        argValues: [k],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiLoadPropertyConstMeta =>
+      const TaskConstMeta(debugName: "load_property", argNames: ["k"]);
+
+  @override
+  Future<bool> crateApiApiLoginByCode({required UiLoginByCodeQuery query}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_ui_login_by_code_query(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiLoginByCodeConstMeta,
+        argValues: [query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiLoginByCodeConstMeta =>
+      const TaskConstMeta(debugName: "login_by_code", argNames: ["query"]);
+
+  @override
+  Future<void> crateApiApiLogout({required bool clearHistory}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(clearHistory, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiLogoutConstMeta,
+        argValues: [clearHistory],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiLogoutConstMeta =>
+      const TaskConstMeta(debugName: "logout", argNames: ["clearHistory"]);
+
+  @override
+  Future<void> crateApiApiPerInChina() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiApiPerInChinaConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiPerInChinaConstMeta =>
+      const TaskConstMeta(debugName: "per_in_china", argNames: []);
+
+  @override
+  Future<bool> crateApiApiPreLogin() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiPreLoginConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiPreLoginConstMeta =>
+      const TaskConstMeta(debugName: "pre_login", argNames: []);
+
+  @override
+  Future<RankingDiff> crateApiApiRankingDiff({
+    required RankMode mode,
+    required String fromDate,
+    required String toDate,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_rank_mode(mode, serializer);
+          sse_encode_String(fromDate, serializer);
+          sse_encode_String(toDate, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_ranking_diff,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiRankingDiffConstMeta,
+        argValues: [mode, fromDate, toDate],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiRankingDiffConstMeta => const TaskConstMeta(
+    debugName: "ranking_diff",
+    argNames: ["mode", "fromDate", "toDate"],
+  );
+
+  @override
+  Future<List<RankingEntryDto>> crateApiApiRankingHistoryOfUser({
+    required RankMode mode,
+    required PlatformInt64 userId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_rank_mode(mode, serializer);
+          sse_encode_i_64(userId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_ranking_entry_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiRankingHistoryOfUserConstMeta,
+        argValues: [mode, userId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiRankingHistoryOfUserConstMeta =>
+      const TaskConstMeta(
+        debugName: "ranking_history_of_user",
+        argNames: ["mode", "userId"],
+      );
+
+  @override
+  Future<List<RankingEntryDto>> crateApiApiRankingSnapshot({
+    required RankMode mode,
+    required String date,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_rank_mode(mode, serializer);
+          sse_encode_String(date, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_ranking_entry_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiRankingSnapshotConstMeta,
+        argValues: [mode, date],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiRankingSnapshotConstMeta => const TaskConstMeta(
+    debugName: "ranking_snapshot",
+    argNames: ["mode", "date"],
+  );
+
+  @override
+  Future<List<String>> crateApiApiRankingSnapshotDates({
+    required RankMode mode,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_rank_mode(mode, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiRankingSnapshotDatesConstMeta,
+        argValues: [mode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiRankingSnapshotDatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "ranking_snapshot_dates",
+        argNames: ["mode"],
+      );
+
+  @override
+  Future<List<RateLimitState>> crateApiApiRateLimitStates() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_rate_limit_state,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
+        constMeta: kCrateApiApiRateLimitStatesConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+  TaskConstMeta get kCrateApiApiRateLimitStatesConstMeta =>
+      const TaskConstMeta(debugName: "rate_limit_states", argNames: []);
 
   @override
-  Future<IllustResponse> crateApiApiIllustFromUrl({required String url}) {
+  Future<void> crateApiApiRecordView({required Illust illust}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(url, serializer);
+          sse_encode_box_autoadd_illust(illust, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 17,
+            funcId: 67,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_illust_response,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiIllustFromUrlConstMeta,
-        argValues: [url],
+        constMeta: kCrateApiApiRecordViewConstMeta,
+        argValues: [illust],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiIllustFromUrlConstMeta =>
-      const TaskConstMeta(debugName: "illust_from_url", argNames: ["url"]);
+  TaskConstMeta get kCrateApiApiRecordViewConstMeta =>
+      const TaskConstMeta(debugName: "record_view", argNames: ["illust"]);
 
   @override
-  Future<String> crateApiApiIllustRankFirstUrl({
-    required UiIllustRankQuery query,
-  }) {
+  Future<Map<String, String>> crateApiApiRefreshBypassSniHosts() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_ui_illust_rank_query(query, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 18,
+            funcId: 68,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_Map_String_String_None,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiIllustRankFirstUrlConstMeta,
-        argValues: [query],
+        constMeta: kCrateApiApiRefreshBypassSniHostsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiIllustRankFirstUrlConstMeta =>
-      const TaskConstMeta(
-        debugName: "illust_rank_first_url",
-        argNames: ["query"],
-      );
+  TaskConstMeta get kCrateApiApiRefreshBypassSniHostsConstMeta =>
+      const TaskConstMeta(debugName: "refresh_bypass_sni_hosts", argNames: []);
 
   @override
-  Future<String> crateApiApiIllustRecommendedFirstUrl() {
+  Future<void> crateApiApiRemoveAccount({required PlatformInt64 userId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(userId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 19,
+            funcId: 69,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiIllustRecommendedFirstUrlConstMeta,
-        argValues: [],
+        constMeta: kCrateApiApiRemoveAccountConstMeta,
+        argValues: [userId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiIllustRecommendedFirstUrlConstMeta =>
-      const TaskConstMeta(
-        debugName: "illust_recommended_first_url",
-        argNames: [],
-      );
+  TaskConstMeta get kCrateApiApiRemoveAccountConstMeta =>
+      const TaskConstMeta(debugName: "remove_account", argNames: ["userId"]);
 
   @override
-  Future<String> crateApiApiIllustSearchFirstUrl({
-    required UiIllustSearchQuery query,
-  }) {
+  Future<String> crateApiApiRequestUrl({required String params}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_ui_illust_search_query(query, serializer);
+          sse_encode_String(params, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 70,
             port: port_,
           );
         },
@@ -809,305 +2481,334 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiIllustSearchFirstUrlConstMeta,
-        argValues: [query],
+        constMeta: kCrateApiApiRequestUrlConstMeta,
+        argValues: [params],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiIllustSearchFirstUrlConstMeta =>
-      const TaskConstMeta(
-        debugName: "illust_search_first_url",
-        argNames: ["query"],
-      );
+  TaskConstMeta get kCrateApiApiRequestUrlConstMeta =>
+      const TaskConstMeta(debugName: "request_url", argNames: ["params"]);
 
   @override
-  Future<IllustTrendingTags> crateApiApiIllustTrendingTags() {
+  Future<void> crateApiApiRetryDownloadTask({required PlatformInt64 id}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(id, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 71,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_illust_trending_tags,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiIllustTrendingTagsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiApiRetryDownloadTaskConstMeta,
+        argValues: [id],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiIllustTrendingTagsConstMeta =>
-      const TaskConstMeta(debugName: "illust_trending_tags", argNames: []);
+  TaskConstMeta get kCrateApiApiRetryDownloadTaskConstMeta =>
+      const TaskConstMeta(debugName: "retry_download_task", argNames: ["id"]);
 
   @override
-  Future<String> crateApiApiIllustTrendingTagsUrl() {
+  Future<void> crateApiApiSaveProperty({required String k, required String v}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(k, serializer);
+          sse_encode_String(v, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 22,
+            funcId: 72,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiIllustTrendingTagsUrlConstMeta,
-        argValues: [],
+        constMeta: kCrateApiApiSavePropertyConstMeta,
+        argValues: [k, v],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiIllustTrendingTagsUrlConstMeta =>
-      const TaskConstMeta(debugName: "illust_trending_tags_url", argNames: []);
+  TaskConstMeta get kCrateApiApiSavePropertyConstMeta =>
+      const TaskConstMeta(debugName: "save_property", argNames: ["k", "v"]);
 
   @override
-  Future<void> crateApiApiInit({required String root}) {
+  Future<List<ViewHistoryDto>> crateApiApiSearchHistory({
+    required String keyword,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(root, serializer);
+          sse_encode_String(keyword, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 23,
+            funcId: 73,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_view_history_dto,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiInitConstMeta,
-        argValues: [root],
+        constMeta: kCrateApiApiSearchHistoryConstMeta,
+        argValues: [keyword],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiInitConstMeta =>
-      const TaskConstMeta(debugName: "init", argNames: ["root"]);
+  TaskConstMeta get kCrateApiApiSearchHistoryConstMeta =>
+      const TaskConstMeta(debugName: "search_history", argNames: ["keyword"]);
 
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Future<void> crateApiApiSetConnectionProfile({
+    required ConnectionProfile profile,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_connection_profile(profile, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 24,
+            funcId: 74,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiApiSetConnectionProfileConstMeta,
+        argValues: [profile],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiApiSetConnectionProfileConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_connection_profile",
+        argNames: ["profile"],
+      );
 
   @override
-  Future<String> crateApiApiLoadPixivImage({required String url}) {
+  Future<void> crateApiApiSetDohSettings({required DohSettings settings}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(url, serializer);
+          sse_encode_box_autoadd_doh_settings(settings, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 25,
+            funcId: 75,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiLoadPixivImageConstMeta,
-        argValues: [url],
+        constMeta: kCrateApiApiSetDohSettingsConstMeta,
+        argValues: [settings],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiLoadPixivImageConstMeta =>
-      const TaskConstMeta(debugName: "load_pixiv_image", argNames: ["url"]);
+  TaskConstMeta get kCrateApiApiSetDohSettingsConstMeta => const TaskConstMeta(
+    debugName: "set_doh_settings",
+    argNames: ["settings"],
+  );
 
   @override
-  Future<String> crateApiApiLoadProperty({required String k}) {
+  Future<void> crateApiApiSetIllustFilter({required IllustFilter filter}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(k, serializer);
+          sse_encode_box_autoadd_illust_filter(filter, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 26,
+            funcId: 76,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiLoadPropertyConstMeta,
-        argValues: [k],
+        constMeta: kCrateApiApiSetIllustFilterConstMeta,
+        argValues: [filter],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiLoadPropertyConstMeta =>
-      const TaskConstMeta(debugName: "load_property", argNames: ["k"]);
+  TaskConstMeta get kCrateApiApiSetIllustFilterConstMeta =>
+      const TaskConstMeta(debugName: "set_illust_filter", argNames: ["filter"]);
 
   @override
-  Future<bool> crateApiApiLoginByCode({required UiLoginByCodeQuery query}) {
+  Future<void> crateApiApiSetInChina({required bool value}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_ui_login_by_code_query(query, serializer);
+          sse_encode_bool(value, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 27,
+            funcId: 77,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiApiLoginByCodeConstMeta,
-        argValues: [query],
+        constMeta: kCrateApiApiSetInChinaConstMeta,
+        argValues: [value],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiLoginByCodeConstMeta =>
-      const TaskConstMeta(debugName: "login_by_code", argNames: ["query"]);
+  TaskConstMeta get kCrateApiApiSetInChinaConstMeta =>
+      const TaskConstMeta(debugName: "set_in_china", argNames: ["value"]);
 
   @override
-  Future<void> crateApiApiPerInChina() {
+  Future<void> crateApiApiSetProxySettings({required ProxySettings settings}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_proxy_settings(settings, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 28,
+            funcId: 78,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiPerInChinaConstMeta,
-        argValues: [],
+        constMeta: kCrateApiApiSetProxySettingsConstMeta,
+        argValues: [settings],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiPerInChinaConstMeta =>
-      const TaskConstMeta(debugName: "per_in_china", argNames: []);
+  TaskConstMeta get kCrateApiApiSetProxySettingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_proxy_settings",
+        argNames: ["settings"],
+      );
 
   @override
-  Future<bool> crateApiApiPreLogin() {
+  Future<void> crateApiApiSetRankingCaptureModes({
+    required List<RankMode> modes,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_rank_mode(modes, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 29,
+            funcId: 79,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiPreLoginConstMeta,
-        argValues: [],
+        constMeta: kCrateApiApiSetRankingCaptureModesConstMeta,
+        argValues: [modes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiPreLoginConstMeta =>
-      const TaskConstMeta(debugName: "pre_login", argNames: []);
+  TaskConstMeta get kCrateApiApiSetRankingCaptureModesConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_ranking_capture_modes",
+        argNames: ["modes"],
+      );
 
   @override
-  Future<String> crateApiApiRequestUrl({required String params}) {
+  Future<void> crateApiApiSetResponseCacheOffline({required bool value}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(params, serializer);
+          sse_encode_bool(value, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 30,
+            funcId: 80,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiRequestUrlConstMeta,
-        argValues: [params],
+        constMeta: kCrateApiApiSetResponseCacheOfflineConstMeta,
+        argValues: [value],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiRequestUrlConstMeta =>
-      const TaskConstMeta(debugName: "request_url", argNames: ["params"]);
+  TaskConstMeta get kCrateApiApiSetResponseCacheOfflineConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_response_cache_offline",
+        argNames: ["value"],
+      );
 
   @override
-  Future<void> crateApiApiRetryDownloadTask({required PlatformInt64 id}) {
+  Future<void> crateApiApiSetServerUrls({
+    required String app,
+    required String oauth,
+    required String image,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(id, serializer);
+          sse_encode_String(app, serializer);
+          sse_encode_String(oauth, serializer);
+          sse_encode_String(image, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 31,
+            funcId: 81,
             port: port_,
           );
         },
@@ -1115,28 +2816,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiRetryDownloadTaskConstMeta,
-        argValues: [id],
+        constMeta: kCrateApiApiSetServerUrlsConstMeta,
+        argValues: [app, oauth, image],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiRetryDownloadTaskConstMeta =>
-      const TaskConstMeta(debugName: "retry_download_task", argNames: ["id"]);
+  TaskConstMeta get kCrateApiApiSetServerUrlsConstMeta => const TaskConstMeta(
+    debugName: "set_server_urls",
+    argNames: ["app", "oauth", "image"],
+  );
 
   @override
-  Future<void> crateApiApiSaveProperty({required String k, required String v}) {
+  Future<void> crateApiApiSwitchAccount({required PlatformInt64 userId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(k, serializer);
-          sse_encode_String(v, serializer);
+          sse_encode_i_64(userId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 32,
+            funcId: 82,
             port: port_,
           );
         },
@@ -1144,43 +2846,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiSavePropertyConstMeta,
-        argValues: [k, v],
+        constMeta: kCrateApiApiSwitchAccountConstMeta,
+        argValues: [userId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiSavePropertyConstMeta =>
-      const TaskConstMeta(debugName: "save_property", argNames: ["k", "v"]);
+  TaskConstMeta get kCrateApiApiSwitchAccountConstMeta =>
+      const TaskConstMeta(debugName: "switch_account", argNames: ["userId"]);
 
   @override
-  Future<void> crateApiApiSetInChina({required bool value}) {
+  Future<List<ProxyTestResult>> crateApiApiTestProxy() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_bool(value, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 33,
+            funcId: 83,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_proxy_test_result,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiApiSetInChinaConstMeta,
-        argValues: [value],
+        constMeta: kCrateApiApiTestProxyConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiSetInChinaConstMeta =>
-      const TaskConstMeta(debugName: "set_in_china", argNames: ["value"]);
+  TaskConstMeta get kCrateApiApiTestProxyConstMeta =>
+      const TaskConstMeta(debugName: "test_proxy", argNames: []);
 
   @override
   Future<void> crateApiApiUnfollowUser({required PlatformInt64 userId}) {
@@ -1192,7 +2893,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 34,
+            funcId: 84,
             port: port_,
           );
         },
@@ -1228,7 +2929,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 35,
+            funcId: 85,
             port: port_,
           );
         },
@@ -1236,23 +2937,59 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiUpdateDownloadTaskStatusConstMeta,
-        argValues: [id, status, progress, errorMessage],
+        constMeta: kCrateApiApiUpdateDownloadTaskStatusConstMeta,
+        argValues: [id, status, progress, errorMessage],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiApiUpdateDownloadTaskStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "update_download_task_status",
+        argNames: ["id", "status", "progress", "errorMessage"],
+      );
+
+  @override
+  Future<IllustResponse> crateApiApiUserBookmarks({
+    required PlatformInt64 userId,
+    required Restrict restrict,
+    String? tag,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(userId, serializer);
+          sse_encode_restrict(restrict, serializer);
+          sse_encode_opt_String(tag, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 86,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_illust_response,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiApiUserBookmarksConstMeta,
+        argValues: [userId, restrict, tag],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiUpdateDownloadTaskStatusConstMeta =>
-      const TaskConstMeta(
-        debugName: "update_download_task_status",
-        argNames: ["id", "status", "progress", "errorMessage"],
-      );
+  TaskConstMeta get kCrateApiApiUserBookmarksConstMeta => const TaskConstMeta(
+    debugName: "user_bookmarks",
+    argNames: ["userId", "restrict", "tag"],
+  );
 
   @override
-  Future<IllustResponse> crateApiApiUserBookmarks({
+  Future<String> crateApiApiUserBookmarksFirstUrl({
     required PlatformInt64 userId,
-    required String restrict,
+    required Restrict restrict,
     String? tag,
   }) {
     return handler.executeNormal(
@@ -1260,30 +2997,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_i_64(userId, serializer);
-          sse_encode_String(restrict, serializer);
+          sse_encode_restrict(restrict, serializer);
           sse_encode_opt_String(tag, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 36,
+            funcId: 87,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_illust_response,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiApiUserBookmarksConstMeta,
+        constMeta: kCrateApiApiUserBookmarksFirstUrlConstMeta,
         argValues: [userId, restrict, tag],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiApiUserBookmarksConstMeta => const TaskConstMeta(
-    debugName: "user_bookmarks",
-    argNames: ["userId", "restrict", "tag"],
-  );
+  TaskConstMeta get kCrateApiApiUserBookmarksFirstUrlConstMeta =>
+      const TaskConstMeta(
+        debugName: "user_bookmarks_first_url",
+        argNames: ["userId", "restrict", "tag"],
+      );
 
   @override
   Future<UserDetail> crateApiApiUserDetail({required PlatformInt64 userId}) {
@@ -1295,7 +3033,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 37,
+            funcId: 88,
             port: port_,
           );
         },
@@ -1316,18 +3054,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @override
   Future<UserPreviewsResponse> crateApiApiUserFollowing({
     required PlatformInt64 userId,
-    required String restrict,
+    required Restrict restrict,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_i_64(userId, serializer);
-          sse_encode_String(restrict, serializer);
+          sse_encode_restrict(restrict, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 38,
+            funcId: 89,
             port: port_,
           );
         },
@@ -1359,7 +3097,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 39,
+            funcId: 90,
             port: port_,
           );
         },
@@ -1392,7 +3130,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 40,
+            funcId: 91,
             port: port_,
           );
         },
@@ -1419,18 +3157,110 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return AnyhowException(raw as String);
   }
 
+  @protected
+  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_string(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  RustStreamSink<AuthEvent> dco_decode_StreamSink_auth_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<IllustResponse> dco_decode_StreamSink_illust_response_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   String dco_decode_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as String;
   }
 
+  @protected
+  AuthEvent dco_decode_auth_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AuthEvent(
+      kind: dco_decode_auth_event_kind(arr[0]),
+      reason: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  AuthEventKind dco_decode_auth_event_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AuthEventKind.values[raw as int];
+  }
+
   @protected
   bool dco_decode_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as bool;
   }
 
+  @protected
+  ConnectionProfile dco_decode_box_autoadd_connection_profile(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_connection_profile(raw);
+  }
+
+  @protected
+  DohSettings dco_decode_box_autoadd_doh_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_doh_settings(raw);
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  Illust dco_decode_box_autoadd_illust(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_illust(raw);
+  }
+
+  @protected
+  IllustFilter dco_decode_box_autoadd_illust_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_illust_filter(raw);
+  }
+
+  @protected
+  IllustResponse dco_decode_box_autoadd_illust_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_illust_response(raw);
+  }
+
+  @protected
+  ImagePlaceholderDto dco_decode_box_autoadd_image_placeholder_dto(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_image_placeholder_dto(raw);
+  }
+
+  @protected
+  ProxySettings dco_decode_box_autoadd_proxy_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_proxy_settings(raw);
+  }
+
   @protected
   Series dco_decode_box_autoadd_series(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1465,6 +3295,56 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_ui_login_by_code_query(raw);
   }
 
+  @protected
+  BypassSniIpStatus dco_decode_bypass_sni_ip_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return BypassSniIpStatus(
+      host: dco_decode_String(arr[0]),
+      ip: dco_decode_String(arr[1]),
+      selected: dco_decode_bool(arr[2]),
+      checked: dco_decode_bool(arr[3]),
+      healthy: dco_decode_bool(arr[4]),
+      tcpMs: dco_decode_i_64(arr[5]),
+      tlsMs: dco_decode_i_64(arr[6]),
+      error: dco_decode_String(arr[7]),
+      checkedTime: dco_decode_i_64(arr[8]),
+    );
+  }
+
+  @protected
+  ConnectionProfile dco_decode_connection_profile(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ConnectionProfile(
+      connectTimeoutSecs: dco_decode_u_64(arr[0]),
+      readTimeoutSecs: dco_decode_u_64(arr[1]),
+      totalTimeoutSecs: dco_decode_u_64(arr[2]),
+      poolMaxIdlePerHost: dco_decode_usize(arr[3]),
+      poolIdleTimeoutSecs: dco_decode_u_64(arr[4]),
+      tcpKeepaliveSecs: dco_decode_u_64(arr[5]),
+      http2: dco_decode_bool(arr[6]),
+      compression: dco_decode_bool(arr[7]),
+    );
+  }
+
+  @protected
+  DohSettings dco_decode_doh_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DohSettings(
+      enabled: dco_decode_bool(arr[0]),
+      endpoints: dco_decode_list_String(arr[1]),
+      updateBypassHosts: dco_decode_bool(arr[2]),
+    );
+  }
+
   @protected
   DownloadTaskDto dco_decode_download_task_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1505,8 +3385,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Illust dco_decode_illust(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 23)
-      throw Exception('unexpected arr length: expect 23 but see ${arr.length}');
+    if (arr.length != 24)
+      throw Exception('unexpected arr length: expect 24 but see ${arr.length}');
     return Illust(
       id: dco_decode_i_64(arr[0]),
       title: dco_decode_String(arr[1]),
@@ -1531,6 +3411,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       isBookmarked: dco_decode_bool(arr[20]),
       visible: dco_decode_bool(arr[21]),
       isMuted: dco_decode_bool(arr[22]),
+      illustAiType: dco_decode_i_64(arr[23]),
+    );
+  }
+
+  @protected
+  IllustFilter dco_decode_illust_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return IllustFilter(
+      mutedTags: dco_decode_list_String(arr[0]),
+      mutedUserIds: dco_decode_list_prim_i_64_strict(arr[1]),
+      hideAi: dco_decode_bool(arr[2]),
+      hideR18: dco_decode_bool(arr[3]),
+      hideUgoira: dco_decode_bool(arr[4]),
     );
   }
 
@@ -1538,11 +3434,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   IllustResponse dco_decode_illust_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
     return IllustResponse(
       illusts: dco_decode_list_illust(arr[0]),
       nextUrl: dco_decode_opt_String(arr[1]),
+      hiddenCount: dco_decode_i_64(arr[2]),
+      stale: dco_decode_bool(arr[3]),
     );
   }
 
@@ -1555,12 +3453,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return IllustTrendingTags(trendTags: dco_decode_list_trend_tag(arr[0]));
   }
 
+  @protected
+  ImagePlaceholderDto dco_decode_image_placeholder_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ImagePlaceholderDto(
+      url: dco_decode_String(arr[0]),
+      blurhash: dco_decode_String(arr[1]),
+      palette: dco_decode_list_prim_u_32_strict(arr[2]),
+      width: dco_decode_i_32(arr[3]),
+      height: dco_decode_i_32(arr[4]),
+    );
+  }
+
   @protected
   List<String> dco_decode_list_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_String).toList();
   }
 
+  @protected
+  List<BypassSniIpStatus> dco_decode_list_bypass_sni_ip_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_bypass_sni_ip_status).toList();
+  }
+
   @protected
   List<DownloadTaskDto> dco_decode_list_download_task_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1579,12 +3498,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_meta_page).toList();
   }
 
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeInt64List(raw);
+  }
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint32List;
+  }
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as Uint8List;
   }
 
+  @protected
+  List<ProxyTestResult> dco_decode_list_proxy_test_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_proxy_test_result).toList();
+  }
+
+  @protected
+  List<RankMode> dco_decode_list_rank_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_rank_mode).toList();
+  }
+
+  @protected
+  List<RankingEntryDto> dco_decode_list_ranking_entry_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_ranking_entry_dto).toList();
+  }
+
+  @protected
+  List<RankingMoveDto> dco_decode_list_ranking_move_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_ranking_move_dto).toList();
+  }
+
+  @protected
+  List<RateLimitState> dco_decode_list_rate_limit_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_rate_limit_state).toList();
+  }
+
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  }
+
+  @protected
+  List<SimilarImageDto> dco_decode_list_similar_image_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_similar_image_dto).toList();
+  }
+
   @protected
   List<Tag> dco_decode_list_tag(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1597,12 +3570,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_trend_tag).toList();
   }
 
+  @protected
+  List<UiAccount> dco_decode_list_ui_account(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_ui_account).toList();
+  }
+
   @protected
   List<UserPreview> dco_decode_list_user_preview(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_user_preview).toList();
   }
 
+  @protected
+  List<ViewHistoryDto> dco_decode_list_view_history_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_view_history_dto).toList();
+  }
+
   @protected
   LoginUrl dco_decode_login_url(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1666,6 +3651,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_String(raw);
   }
 
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  IllustResponse? dco_decode_opt_box_autoadd_illust_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_illust_response(raw);
+  }
+
+  @protected
+  ImagePlaceholderDto? dco_decode_opt_box_autoadd_image_placeholder_dto(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_image_placeholder_dto(raw);
+  }
+
   @protected
   Series? dco_decode_opt_box_autoadd_series(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1737,6 +3744,129 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ProxySettings dco_decode_proxy_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ProxySettings(
+      url: dco_decode_String(arr[0]),
+      username: dco_decode_String(arr[1]),
+      password: dco_decode_String(arr[2]),
+      noProxy: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  ProxyTestResult dco_decode_proxy_test_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ProxyTestResult(
+      url: dco_decode_String(arr[0]),
+      latencyMs: dco_decode_i_64(arr[1]),
+      status: dco_decode_i_32(arr[2]),
+      error: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  RankMode dco_decode_rank_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RankMode.values[raw as int];
+  }
+
+  @protected
+  RankingDiff dco_decode_ranking_diff(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return RankingDiff(
+      mode: dco_decode_rank_mode(arr[0]),
+      fromDate: dco_decode_String(arr[1]),
+      toDate: dco_decode_String(arr[2]),
+      newEntries: dco_decode_list_ranking_move_dto(arr[3]),
+      climbed: dco_decode_list_ranking_move_dto(arr[4]),
+      fell: dco_decode_list_ranking_move_dto(arr[5]),
+      dropped: dco_decode_list_ranking_move_dto(arr[6]),
+    );
+  }
+
+  @protected
+  RankingEntryDto dco_decode_ranking_entry_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RankingEntryDto(
+      mode: dco_decode_rank_mode(arr[0]),
+      date: dco_decode_String(arr[1]),
+      rank: dco_decode_i_64(arr[2]),
+      illust: dco_decode_illust(arr[3]),
+      capturedTime: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  RankingMoveDto dco_decode_ranking_move_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RankingMoveDto(
+      illust: dco_decode_illust(arr[0]),
+      fromRank: dco_decode_opt_box_autoadd_i_64(arr[1]),
+      toRank: dco_decode_opt_box_autoadd_i_64(arr[2]),
+    );
+  }
+
+  @protected
+  RateLimitState dco_decode_rate_limit_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return RateLimitState(
+      host: dco_decode_String(arr[0]),
+      available: dco_decode_i_64(arr[1]),
+      capacity: dco_decode_i_64(arr[2]),
+      waiting: dco_decode_i_64(arr[3]),
+      pausedMs: dco_decode_i_64(arr[4]),
+      throttledCount: dco_decode_i_64(arr[5]),
+    );
+  }
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  }
+
+  @protected
+  Restrict dco_decode_restrict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Restrict.values[raw as int];
+  }
+
+  @protected
+  SearchSort dco_decode_search_sort(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SearchSort.values[raw as int];
+  }
+
+  @protected
+  SearchTarget dco_decode_search_target(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SearchTarget.values[raw as int];
+  }
+
   @protected
   Series dco_decode_series(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1749,6 +3879,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  SimilarImageDto dco_decode_similar_image_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SimilarImageDto(
+      url: dco_decode_String(arr[0]),
+      path: dco_decode_String(arr[1]),
+      distance: dco_decode_u_32(arr[2]),
+      downloaded: dco_decode_bool(arr[3]),
+    );
+  }
+
   @protected
   Tag dco_decode_tag(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1774,12 +3918,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
   @protected
   int dco_decode_u_8(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as int;
   }
 
+  @protected
+  UiAccount dco_decode_ui_account(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return UiAccount(
+      userId: dco_decode_i_64(arr[0]),
+      name: dco_decode_String(arr[1]),
+      account: dco_decode_String(arr[2]),
+      profileImageUrl: dco_decode_String(arr[3]),
+      isPremium: dco_decode_bool(arr[4]),
+      isCurrent: dco_decode_bool(arr[5]),
+    );
+  }
+
   @protected
   UiCurrentUser dco_decode_ui_current_user(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1802,7 +3974,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     if (arr.length != 2)
       throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
     return UiIllustRankQuery(
-      mode: dco_decode_String(arr[0]),
+      mode: dco_decode_rank_mode(arr[0]),
       date: dco_decode_String(arr[1]),
     );
   }
@@ -1815,8 +3987,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return UiIllustSearchQuery(
       word: dco_decode_String(arr[0]),
-      searchTarget: dco_decode_String(arr[1]),
-      sort: dco_decode_String(arr[2]),
+      searchTarget: dco_decode_search_target(arr[1]),
+      sort: dco_decode_search_sort(arr[2]),
     );
   }
 
@@ -1859,13 +4031,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   UserDetail dco_decode_user_detail(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
     return UserDetail(
       profile: dco_decode_profile(arr[0]),
       profilePublicity: dco_decode_profile_publicity(arr[1]),
       user: dco_decode_user(arr[2]),
       workspace: dco_decode_workspace(arr[3]),
+      stale: dco_decode_bool(arr[4]),
     );
   }
 
@@ -1909,6 +4082,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  ViewHistoryDto dco_decode_view_history_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ViewHistoryDto(
+      illust: dco_decode_illust(arr[0]),
+      viewCount: dco_decode_i_32(arr[1]),
+      firstViewTime: dco_decode_i_64(arr[2]),
+      lastViewTime: dco_decode_i_64(arr[3]),
+    );
+  }
+
   @protected
   Workspace dco_decode_workspace(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1939,6 +4132,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return AnyhowException(inner);
   }
 
+  @protected
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_string(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  RustStreamSink<AuthEvent> sse_decode_StreamSink_auth_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<IllustResponse> sse_decode_StreamSink_illust_response_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   String sse_decode_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1946,12 +4164,87 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return utf8.decoder.convert(inner);
   }
 
+  @protected
+  AuthEvent sse_decode_auth_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_auth_event_kind(deserializer);
+    var var_reason = sse_decode_String(deserializer);
+    return AuthEvent(kind: var_kind, reason: var_reason);
+  }
+
+  @protected
+  AuthEventKind sse_decode_auth_event_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AuthEventKind.values[inner];
+  }
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return deserializer.buffer.getUint8() != 0;
   }
 
+  @protected
+  ConnectionProfile sse_decode_box_autoadd_connection_profile(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_connection_profile(deserializer));
+  }
+
+  @protected
+  DohSettings sse_decode_box_autoadd_doh_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_doh_settings(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  Illust sse_decode_box_autoadd_illust(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_illust(deserializer));
+  }
+
+  @protected
+  IllustFilter sse_decode_box_autoadd_illust_filter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_illust_filter(deserializer));
+  }
+
+  @protected
+  IllustResponse sse_decode_box_autoadd_illust_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_illust_response(deserializer));
+  }
+
+  @protected
+  ImagePlaceholderDto sse_decode_box_autoadd_image_placeholder_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_image_placeholder_dto(deserializer));
+  }
+
+  @protected
+  ProxySettings sse_decode_box_autoadd_proxy_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_proxy_settings(deserializer));
+  }
+
   @protected
   Series sse_decode_box_autoadd_series(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1990,6 +4283,71 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_ui_login_by_code_query(deserializer));
   }
 
+  @protected
+  BypassSniIpStatus sse_decode_bypass_sni_ip_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_host = sse_decode_String(deserializer);
+    var var_ip = sse_decode_String(deserializer);
+    var var_selected = sse_decode_bool(deserializer);
+    var var_checked = sse_decode_bool(deserializer);
+    var var_healthy = sse_decode_bool(deserializer);
+    var var_tcpMs = sse_decode_i_64(deserializer);
+    var var_tlsMs = sse_decode_i_64(deserializer);
+    var var_error = sse_decode_String(deserializer);
+    var var_checkedTime = sse_decode_i_64(deserializer);
+    return BypassSniIpStatus(
+      host: var_host,
+      ip: var_ip,
+      selected: var_selected,
+      checked: var_checked,
+      healthy: var_healthy,
+      tcpMs: var_tcpMs,
+      tlsMs: var_tlsMs,
+      error: var_error,
+      checkedTime: var_checkedTime,
+    );
+  }
+
+  @protected
+  ConnectionProfile sse_decode_connection_profile(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_connectTimeoutSecs = sse_decode_u_64(deserializer);
+    var var_readTimeoutSecs = sse_decode_u_64(deserializer);
+    var var_totalTimeoutSecs = sse_decode_u_64(deserializer);
+    var var_poolMaxIdlePerHost = sse_decode_usize(deserializer);
+    var var_poolIdleTimeoutSecs = sse_decode_u_64(deserializer);
+    var var_tcpKeepaliveSecs = sse_decode_u_64(deserializer);
+    var var_http2 = sse_decode_bool(deserializer);
+    var var_compression = sse_decode_bool(deserializer);
+    return ConnectionProfile(
+      connectTimeoutSecs: var_connectTimeoutSecs,
+      readTimeoutSecs: var_readTimeoutSecs,
+      totalTimeoutSecs: var_totalTimeoutSecs,
+      poolMaxIdlePerHost: var_poolMaxIdlePerHost,
+      poolIdleTimeoutSecs: var_poolIdleTimeoutSecs,
+      tcpKeepaliveSecs: var_tcpKeepaliveSecs,
+      http2: var_http2,
+      compression: var_compression,
+    );
+  }
+
+  @protected
+  DohSettings sse_decode_doh_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_endpoints = sse_decode_list_String(deserializer);
+    var var_updateBypassHosts = sse_decode_bool(deserializer);
+    return DohSettings(
+      enabled: var_enabled,
+      endpoints: var_endpoints,
+      updateBypassHosts: var_updateBypassHosts,
+    );
+  }
+
   @protected
   DownloadTaskDto sse_decode_download_task_dto(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2063,6 +4421,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_isBookmarked = sse_decode_bool(deserializer);
     var var_visible = sse_decode_bool(deserializer);
     var var_isMuted = sse_decode_bool(deserializer);
+    var var_illustAiType = sse_decode_i_64(deserializer);
     return Illust(
       id: var_id,
       title: var_title,
@@ -2087,6 +4446,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       isBookmarked: var_isBookmarked,
       visible: var_visible,
       isMuted: var_isMuted,
+      illustAiType: var_illustAiType,
+    );
+  }
+
+  @protected
+  IllustFilter sse_decode_illust_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mutedTags = sse_decode_list_String(deserializer);
+    var var_mutedUserIds = sse_decode_list_prim_i_64_strict(deserializer);
+    var var_hideAi = sse_decode_bool(deserializer);
+    var var_hideR18 = sse_decode_bool(deserializer);
+    var var_hideUgoira = sse_decode_bool(deserializer);
+    return IllustFilter(
+      mutedTags: var_mutedTags,
+      mutedUserIds: var_mutedUserIds,
+      hideAi: var_hideAi,
+      hideR18: var_hideR18,
+      hideUgoira: var_hideUgoira,
     );
   }
 
@@ -2095,7 +4472,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_illusts = sse_decode_list_illust(deserializer);
     var var_nextUrl = sse_decode_opt_String(deserializer);
-    return IllustResponse(illusts: var_illusts, nextUrl: var_nextUrl);
+    var var_hiddenCount = sse_decode_i_64(deserializer);
+    var var_stale = sse_decode_bool(deserializer);
+    return IllustResponse(
+      illusts: var_illusts,
+      nextUrl: var_nextUrl,
+      hiddenCount: var_hiddenCount,
+      stale: var_stale,
+    );
   }
 
   @protected
@@ -2107,6 +4491,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return IllustTrendingTags(trendTags: var_trendTags);
   }
 
+  @protected
+  ImagePlaceholderDto sse_decode_image_placeholder_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_blurhash = sse_decode_String(deserializer);
+    var var_palette = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_width = sse_decode_i_32(deserializer);
+    var var_height = sse_decode_i_32(deserializer);
+    return ImagePlaceholderDto(
+      url: var_url,
+      blurhash: var_blurhash,
+      palette: var_palette,
+      width: var_width,
+      height: var_height,
+    );
+  }
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2119,6 +4522,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<BypassSniIpStatus> sse_decode_list_bypass_sni_ip_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BypassSniIpStatus>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_bypass_sni_ip_status(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<DownloadTaskDto> sse_decode_list_download_task_dto(
     SseDeserializer deserializer,
@@ -2140,28 +4557,138 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var len_ = sse_decode_i_32(deserializer);
     var ans_ = <Illust>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_illust(deserializer));
+      ans_.add(sse_decode_illust(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MetaPage> sse_decode_list_meta_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MetaPage>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_meta_page(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt64List(len_);
+  }
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<ProxyTestResult> sse_decode_list_proxy_test_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ProxyTestResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_proxy_test_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RankMode> sse_decode_list_rank_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RankMode>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_rank_mode(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RankingEntryDto> sse_decode_list_ranking_entry_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RankingEntryDto>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_ranking_entry_dto(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RankingMoveDto> sse_decode_list_ranking_move_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RankingMoveDto>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_ranking_move_dto(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RateLimitState> sse_decode_list_rate_limit_state(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RateLimitState>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_rate_limit_state(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<MetaPage> sse_decode_list_meta_page(SseDeserializer deserializer) {
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MetaPage>[];
+    var ans_ = <(String, String)>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_meta_page(deserializer));
+      ans_.add(sse_decode_record_string_string(deserializer));
     }
     return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  List<SimilarImageDto> sse_decode_list_similar_image_dto(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
     var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    var ans_ = <SimilarImageDto>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_similar_image_dto(deserializer));
+    }
+    return ans_;
   }
 
   @protected
@@ -2188,6 +4715,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<UiAccount> sse_decode_list_ui_account(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <UiAccount>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_ui_account(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<UserPreview> sse_decode_list_user_preview(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2200,6 +4739,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<ViewHistoryDto> sse_decode_list_view_history_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ViewHistoryDto>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_view_history_dto(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   LoginUrl sse_decode_login_url(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2263,6 +4816,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  IllustResponse? sse_decode_opt_box_autoadd_illust_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_illust_response(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ImagePlaceholderDto? sse_decode_opt_box_autoadd_image_placeholder_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_image_placeholder_dto(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   Series? sse_decode_opt_box_autoadd_series(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2368,6 +4958,144 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ProxySettings sse_decode_proxy_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_username = sse_decode_String(deserializer);
+    var var_password = sse_decode_String(deserializer);
+    var var_noProxy = sse_decode_String(deserializer);
+    return ProxySettings(
+      url: var_url,
+      username: var_username,
+      password: var_password,
+      noProxy: var_noProxy,
+    );
+  }
+
+  @protected
+  ProxyTestResult sse_decode_proxy_test_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_latencyMs = sse_decode_i_64(deserializer);
+    var var_status = sse_decode_i_32(deserializer);
+    var var_error = sse_decode_String(deserializer);
+    return ProxyTestResult(
+      url: var_url,
+      latencyMs: var_latencyMs,
+      status: var_status,
+      error: var_error,
+    );
+  }
+
+  @protected
+  RankMode sse_decode_rank_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RankMode.values[inner];
+  }
+
+  @protected
+  RankingDiff sse_decode_ranking_diff(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mode = sse_decode_rank_mode(deserializer);
+    var var_fromDate = sse_decode_String(deserializer);
+    var var_toDate = sse_decode_String(deserializer);
+    var var_newEntries = sse_decode_list_ranking_move_dto(deserializer);
+    var var_climbed = sse_decode_list_ranking_move_dto(deserializer);
+    var var_fell = sse_decode_list_ranking_move_dto(deserializer);
+    var var_dropped = sse_decode_list_ranking_move_dto(deserializer);
+    return RankingDiff(
+      mode: var_mode,
+      fromDate: var_fromDate,
+      toDate: var_toDate,
+      newEntries: var_newEntries,
+      climbed: var_climbed,
+      fell: var_fell,
+      dropped: var_dropped,
+    );
+  }
+
+  @protected
+  RankingEntryDto sse_decode_ranking_entry_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mode = sse_decode_rank_mode(deserializer);
+    var var_date = sse_decode_String(deserializer);
+    var var_rank = sse_decode_i_64(deserializer);
+    var var_illust = sse_decode_illust(deserializer);
+    var var_capturedTime = sse_decode_i_64(deserializer);
+    return RankingEntryDto(
+      mode: var_mode,
+      date: var_date,
+      rank: var_rank,
+      illust: var_illust,
+      capturedTime: var_capturedTime,
+    );
+  }
+
+  @protected
+  RankingMoveDto sse_decode_ranking_move_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_illust = sse_decode_illust(deserializer);
+    var var_fromRank = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_toRank = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return RankingMoveDto(
+      illust: var_illust,
+      fromRank: var_fromRank,
+      toRank: var_toRank,
+    );
+  }
+
+  @protected
+  RateLimitState sse_decode_rate_limit_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_host = sse_decode_String(deserializer);
+    var var_available = sse_decode_i_64(deserializer);
+    var var_capacity = sse_decode_i_64(deserializer);
+    var var_waiting = sse_decode_i_64(deserializer);
+    var var_pausedMs = sse_decode_i_64(deserializer);
+    var var_throttledCount = sse_decode_i_64(deserializer);
+    return RateLimitState(
+      host: var_host,
+      available: var_available,
+      capacity: var_capacity,
+      waiting: var_waiting,
+      pausedMs: var_pausedMs,
+      throttledCount: var_throttledCount,
+    );
+  }
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_String(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  Restrict sse_decode_restrict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Restrict.values[inner];
+  }
+
+  @protected
+  SearchSort sse_decode_search_sort(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SearchSort.values[inner];
+  }
+
+  @protected
+  SearchTarget sse_decode_search_target(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SearchTarget.values[inner];
+  }
+
   @protected
   Series sse_decode_series(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2376,6 +5104,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return Series(id: var_id, title: var_title);
   }
 
+  @protected
+  SimilarImageDto sse_decode_similar_image_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_distance = sse_decode_u_32(deserializer);
+    var var_downloaded = sse_decode_bool(deserializer);
+    return SimilarImageDto(
+      url: var_url,
+      path: var_path,
+      distance: var_distance,
+      downloaded: var_downloaded,
+    );
+  }
+
   @protected
   Tag sse_decode_tag(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2397,12 +5140,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return deserializer.buffer.getUint8();
   }
 
+  @protected
+  UiAccount sse_decode_ui_account(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_userId = sse_decode_i_64(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_account = sse_decode_String(deserializer);
+    var var_profileImageUrl = sse_decode_String(deserializer);
+    var var_isPremium = sse_decode_bool(deserializer);
+    var var_isCurrent = sse_decode_bool(deserializer);
+    return UiAccount(
+      userId: var_userId,
+      name: var_name,
+      account: var_account,
+      profileImageUrl: var_profileImageUrl,
+      isPremium: var_isPremium,
+      isCurrent: var_isCurrent,
+    );
+  }
+
   @protected
   UiCurrentUser sse_decode_ui_current_user(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2425,7 +5199,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_mode = sse_decode_String(deserializer);
+    var var_mode = sse_decode_rank_mode(deserializer);
     var var_date = sse_decode_String(deserializer);
     return UiIllustRankQuery(mode: var_mode, date: var_date);
   }
@@ -2436,8 +5210,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_word = sse_decode_String(deserializer);
-    var var_searchTarget = sse_decode_String(deserializer);
-    var var_sort = sse_decode_String(deserializer);
+    var var_searchTarget = sse_decode_search_target(deserializer);
+    var var_sort = sse_decode_search_sort(deserializer);
     return UiIllustSearchQuery(
       word: var_word,
       searchTarget: var_searchTarget,
@@ -2488,11 +5262,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_profilePublicity = sse_decode_profile_publicity(deserializer);
     var var_user = sse_decode_user(deserializer);
     var var_workspace = sse_decode_workspace(deserializer);
+    var var_stale = sse_decode_bool(deserializer);
     return UserDetail(
       profile: var_profile,
       profilePublicity: var_profilePublicity,
       user: var_user,
       workspace: var_workspace,
+      stale: var_stale,
     );
   }
 
@@ -2539,6 +5315,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  ViewHistoryDto sse_decode_view_history_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_illust = sse_decode_illust(deserializer);
+    var var_viewCount = sse_decode_i_32(deserializer);
+    var var_firstViewTime = sse_decode_i_64(deserializer);
+    var var_lastViewTime = sse_decode_i_64(deserializer);
+    return ViewHistoryDto(
+      illust: var_illust,
+      viewCount: var_viewCount,
+      firstViewTime: var_firstViewTime,
+      lastViewTime: var_lastViewTime,
+    );
+  }
+
   @protected
   Workspace sse_decode_workspace(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2581,16 +5378,147 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.message, serializer);
   }
 
+  @protected
+  void sse_encode_Map_String_String_None(
+    Map<String, String> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_string(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_auth_event_Sse(
+    RustStreamSink<AuthEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_auth_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_illust_response_Sse(
+    RustStreamSink<IllustResponse> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_illust_response,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
+  @protected
+  void sse_encode_auth_event(AuthEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_auth_event_kind(self.kind, serializer);
+    sse_encode_String(self.reason, serializer);
+  }
+
+  @protected
+  void sse_encode_auth_event_kind(
+    AuthEventKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_connection_profile(
+    ConnectionProfile self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_connection_profile(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_doh_settings(
+    DohSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_doh_settings(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_illust(Illust self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_illust(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_illust_filter(
+    IllustFilter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_illust_filter(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_illust_response(
+    IllustResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_illust_response(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_image_placeholder_dto(
+    ImagePlaceholderDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_image_placeholder_dto(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_proxy_settings(
+    ProxySettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_proxy_settings(self, serializer);
   }
 
   @protected
@@ -2635,6 +5563,47 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_ui_login_by_code_query(self, serializer);
   }
 
+  @protected
+  void sse_encode_bypass_sni_ip_status(
+    BypassSniIpStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.host, serializer);
+    sse_encode_String(self.ip, serializer);
+    sse_encode_bool(self.selected, serializer);
+    sse_encode_bool(self.checked, serializer);
+    sse_encode_bool(self.healthy, serializer);
+    sse_encode_i_64(self.tcpMs, serializer);
+    sse_encode_i_64(self.tlsMs, serializer);
+    sse_encode_String(self.error, serializer);
+    sse_encode_i_64(self.checkedTime, serializer);
+  }
+
+  @protected
+  void sse_encode_connection_profile(
+    ConnectionProfile self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.connectTimeoutSecs, serializer);
+    sse_encode_u_64(self.readTimeoutSecs, serializer);
+    sse_encode_u_64(self.totalTimeoutSecs, serializer);
+    sse_encode_usize(self.poolMaxIdlePerHost, serializer);
+    sse_encode_u_64(self.poolIdleTimeoutSecs, serializer);
+    sse_encode_u_64(self.tcpKeepaliveSecs, serializer);
+    sse_encode_bool(self.http2, serializer);
+    sse_encode_bool(self.compression, serializer);
+  }
+
+  @protected
+  void sse_encode_doh_settings(DohSettings self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_list_String(self.endpoints, serializer);
+    sse_encode_bool(self.updateBypassHosts, serializer);
+  }
+
   @protected
   void sse_encode_download_task_dto(
     DownloadTaskDto self,
@@ -2695,6 +5664,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self.isBookmarked, serializer);
     sse_encode_bool(self.visible, serializer);
     sse_encode_bool(self.isMuted, serializer);
+    sse_encode_i_64(self.illustAiType, serializer);
+  }
+
+  @protected
+  void sse_encode_illust_filter(IllustFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.mutedTags, serializer);
+    sse_encode_list_prim_i_64_strict(self.mutedUserIds, serializer);
+    sse_encode_bool(self.hideAi, serializer);
+    sse_encode_bool(self.hideR18, serializer);
+    sse_encode_bool(self.hideUgoira, serializer);
   }
 
   @protected
@@ -2705,6 +5685,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_list_illust(self.illusts, serializer);
     sse_encode_opt_String(self.nextUrl, serializer);
+    sse_encode_i_64(self.hiddenCount, serializer);
+    sse_encode_bool(self.stale, serializer);
   }
 
   @protected
@@ -2716,6 +5698,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_trend_tag(self.trendTags, serializer);
   }
 
+  @protected
+  void sse_encode_image_placeholder_dto(
+    ImagePlaceholderDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.blurhash, serializer);
+    sse_encode_list_prim_u_32_strict(self.palette, serializer);
+    sse_encode_i_32(self.width, serializer);
+    sse_encode_i_32(self.height, serializer);
+  }
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2725,6 +5720,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_bypass_sni_ip_status(
+    List<BypassSniIpStatus> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_bypass_sni_ip_status(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_download_task_dto(
     List<DownloadTaskDto> self,
@@ -2758,6 +5765,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_prim_i_64_strict(
+    Int64List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt64List(self);
+  }
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(self);
+  }
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -2768,6 +5795,90 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8List(self);
   }
 
+  @protected
+  void sse_encode_list_proxy_test_result(
+    List<ProxyTestResult> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_proxy_test_result(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_rank_mode(
+    List<RankMode> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_rank_mode(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_ranking_entry_dto(
+    List<RankingEntryDto> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_ranking_entry_dto(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_ranking_move_dto(
+    List<RankingMoveDto> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_ranking_move_dto(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_rate_limit_state(
+    List<RateLimitState> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_rate_limit_state(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_string(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_similar_image_dto(
+    List<SimilarImageDto> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_similar_image_dto(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_tag(List<Tag> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2789,6 +5900,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_ui_account(
+    List<UiAccount> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_ui_account(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_user_preview(
     List<UserPreview> self,
@@ -2801,6 +5924,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_view_history_dto(
+    List<ViewHistoryDto> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_view_history_dto(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_login_url(LoginUrl self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2856,6 +5991,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_illust_response(
+    IllustResponse? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_illust_response(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_image_placeholder_dto(
+    ImagePlaceholderDto? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_image_placeholder_dto(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_series(
     Series? self,
@@ -2934,6 +6108,111 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.region, serializer);
   }
 
+  @protected
+  void sse_encode_proxy_settings(ProxySettings self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.username, serializer);
+    sse_encode_String(self.password, serializer);
+    sse_encode_String(self.noProxy, serializer);
+  }
+
+  @protected
+  void sse_encode_proxy_test_result(
+    ProxyTestResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_i_64(self.latencyMs, serializer);
+    sse_encode_i_32(self.status, serializer);
+    sse_encode_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_rank_mode(RankMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_ranking_diff(RankingDiff self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_rank_mode(self.mode, serializer);
+    sse_encode_String(self.fromDate, serializer);
+    sse_encode_String(self.toDate, serializer);
+    sse_encode_list_ranking_move_dto(self.newEntries, serializer);
+    sse_encode_list_ranking_move_dto(self.climbed, serializer);
+    sse_encode_list_ranking_move_dto(self.fell, serializer);
+    sse_encode_list_ranking_move_dto(self.dropped, serializer);
+  }
+
+  @protected
+  void sse_encode_ranking_entry_dto(
+    RankingEntryDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_rank_mode(self.mode, serializer);
+    sse_encode_String(self.date, serializer);
+    sse_encode_i_64(self.rank, serializer);
+    sse_encode_illust(self.illust, serializer);
+    sse_encode_i_64(self.capturedTime, serializer);
+  }
+
+  @protected
+  void sse_encode_ranking_move_dto(
+    RankingMoveDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_illust(self.illust, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.fromRank, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.toRank, serializer);
+  }
+
+  @protected
+  void sse_encode_rate_limit_state(
+    RateLimitState self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.host, serializer);
+    sse_encode_i_64(self.available, serializer);
+    sse_encode_i_64(self.capacity, serializer);
+    sse_encode_i_64(self.waiting, serializer);
+    sse_encode_i_64(self.pausedMs, serializer);
+    sse_encode_i_64(self.throttledCount, serializer);
+  }
+
+  @protected
+  void sse_encode_record_string_string(
+    (String, String) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.$1, serializer);
+    sse_encode_String(self.$2, serializer);
+  }
+
+  @protected
+  void sse_encode_restrict(Restrict self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_search_sort(SearchSort self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_search_target(SearchTarget self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_series(Series self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2941,6 +6220,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.title, serializer);
   }
 
+  @protected
+  void sse_encode_similar_image_dto(
+    SimilarImageDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_u_32(self.distance, serializer);
+    sse_encode_bool(self.downloaded, serializer);
+  }
+
   @protected
   void sse_encode_tag(Tag self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2956,12 +6247,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_illust(self.illust, serializer);
   }
 
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
+  }
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putUint8(self);
   }
 
+  @protected
+  void sse_encode_ui_account(UiAccount self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.userId, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.account, serializer);
+    sse_encode_String(self.profileImageUrl, serializer);
+    sse_encode_bool(self.isPremium, serializer);
+    sse_encode_bool(self.isCurrent, serializer);
+  }
+
   @protected
   void sse_encode_ui_current_user(
     UiCurrentUser self,
@@ -2981,7 +6295,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.mode, serializer);
+    sse_encode_rank_mode(self.mode, serializer);
     sse_encode_String(self.date, serializer);
   }
 
@@ -2992,8 +6306,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.word, serializer);
-    sse_encode_String(self.searchTarget, serializer);
-    sse_encode_String(self.sort, serializer);
+    sse_encode_search_target(self.searchTarget, serializer);
+    sse_encode_search_sort(self.sort, serializer);
   }
 
   @protected
@@ -3030,6 +6344,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_profile_publicity(self.profilePublicity, serializer);
     sse_encode_user(self.user, serializer);
     sse_encode_workspace(self.workspace, serializer);
+    sse_encode_bool(self.stale, serializer);
   }
 
   @protected
@@ -3060,6 +6375,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self.isFollowed, serializer);
   }
 
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
+  @protected
+  void sse_encode_view_history_dto(
+    ViewHistoryDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_illust(self.illust, serializer);
+    sse_encode_i_32(self.viewCount, serializer);
+    sse_encode_i_64(self.firstViewTime, serializer);
+    sse_encode_i_64(self.lastViewTime, serializer);
+  }
+
   @protected
   void sse_encode_workspace(Workspace self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -26,12 +26,53 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  Map<String, String> dco_decode_Map_String_String_None(dynamic raw);
+
+  @protected
+  RustStreamSink<AuthEvent> dco_decode_StreamSink_auth_event_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<IllustResponse> dco_decode_StreamSink_illust_response_Sse(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AuthEvent dco_decode_auth_event(dynamic raw);
+
+  @protected
+  AuthEventKind dco_decode_auth_event_kind(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  ConnectionProfile dco_decode_box_autoadd_connection_profile(dynamic raw);
+
+  @protected
+  DohSettings dco_decode_box_autoadd_doh_settings(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  Illust dco_decode_box_autoadd_illust(dynamic raw);
+
+  @protected
+  IllustFilter dco_decode_box_autoadd_illust_filter(dynamic raw);
+
+  @protected
+  IllustResponse dco_decode_box_autoadd_illust_response(dynamic raw);
+
+  @protected
+  ImagePlaceholderDto dco_decode_box_autoadd_image_placeholder_dto(dynamic raw);
+
+  @protected
+  ProxySettings dco_decode_box_autoadd_proxy_settings(dynamic raw);
+
   @protected
   Series dco_decode_box_autoadd_series(dynamic raw);
 
@@ -49,6 +90,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   UiLoginByCodeQuery dco_decode_box_autoadd_ui_login_by_code_query(dynamic raw);
 
+  @protected
+  BypassSniIpStatus dco_decode_bypass_sni_ip_status(dynamic raw);
+
+  @protected
+  ConnectionProfile dco_decode_connection_profile(dynamic raw);
+
+  @protected
+  DohSettings dco_decode_doh_settings(dynamic raw);
+
   @protected
   DownloadTaskDto dco_decode_download_task_dto(dynamic raw);
 
@@ -61,15 +111,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Illust dco_decode_illust(dynamic raw);
 
+  @protected
+  IllustFilter dco_decode_illust_filter(dynamic raw);
+
   @protected
   IllustResponse dco_decode_illust_response(dynamic raw);
 
   @protected
   IllustTrendingTags dco_decode_illust_trending_tags(dynamic raw);
 
+  @protected
+  ImagePlaceholderDto dco_decode_image_placeholder_dto(dynamic raw);
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<BypassSniIpStatus> dco_decode_list_bypass_sni_ip_status(dynamic raw);
+
   @protected
   List<DownloadTaskDto> dco_decode_list_download_task_dto(dynamic raw);
 
@@ -79,18 +138,51 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<MetaPage> dco_decode_list_meta_page(dynamic raw);
 
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw);
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<ProxyTestResult> dco_decode_list_proxy_test_result(dynamic raw);
+
+  @protected
+  List<RankMode> dco_decode_list_rank_mode(dynamic raw);
+
+  @protected
+  List<RankingEntryDto> dco_decode_list_ranking_entry_dto(dynamic raw);
+
+  @protected
+  List<RankingMoveDto> dco_decode_list_ranking_move_dto(dynamic raw);
+
+  @protected
+  List<RateLimitState> dco_decode_list_rate_limit_state(dynamic raw);
+
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
+
+  @protected
+  List<SimilarImageDto> dco_decode_list_similar_image_dto(dynamic raw);
+
   @protected
   List<Tag> dco_decode_list_tag(dynamic raw);
 
   @protected
   List<TrendTag> dco_decode_list_trend_tag(dynamic raw);
 
+  @protected
+  List<UiAccount> dco_decode_list_ui_account(dynamic raw);
+
   @protected
   List<UserPreview> dco_decode_list_user_preview(dynamic raw);
 
+  @protected
+  List<ViewHistoryDto> dco_decode_list_view_history_dto(dynamic raw);
+
   @protected
   LoginUrl dco_decode_login_url(dynamic raw);
 
@@ -109,6 +201,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  IllustResponse? dco_decode_opt_box_autoadd_illust_response(dynamic raw);
+
+  @protected
+  ImagePlaceholderDto? dco_decode_opt_box_autoadd_image_placeholder_dto(
+    dynamic raw,
+  );
+
   @protected
   Series? dco_decode_opt_box_autoadd_series(dynamic raw);
 
@@ -124,18 +227,63 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ProfilePublicity dco_decode_profile_publicity(dynamic raw);
 
+  @protected
+  ProxySettings dco_decode_proxy_settings(dynamic raw);
+
+  @protected
+  ProxyTestResult dco_decode_proxy_test_result(dynamic raw);
+
+  @protected
+  RankMode dco_decode_rank_mode(dynamic raw);
+
+  @protected
+  RankingDiff dco_decode_ranking_diff(dynamic raw);
+
+  @protected
+  RankingEntryDto dco_decode_ranking_entry_dto(dynamic raw);
+
+  @protected
+  RankingMoveDto dco_decode_ranking_move_dto(dynamic raw);
+
+  @protected
+  RateLimitState dco_decode_rate_limit_state(dynamic raw);
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw);
+
+  @protected
+  Restrict dco_decode_restrict(dynamic raw);
+
+  @protected
+  SearchSort dco_decode_search_sort(dynamic raw);
+
+  @protected
+  SearchTarget dco_decode_search_target(dynamic raw);
+
   @protected
   Series dco_decode_series(dynamic raw);
 
+  @protected
+  SimilarImageDto dco_decode_similar_image_dto(dynamic raw);
+
   @protected
   Tag dco_decode_tag(dynamic raw);
 
   @protected
   TrendTag dco_decode_trend_tag(dynamic raw);
 
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
   @protected
   int dco_decode_u_8(dynamic raw);
 
+  @protected
+  UiAccount dco_decode_ui_account(dynamic raw);
+
   @protected
   UiCurrentUser dco_decode_ui_current_user(dynamic raw);
 
@@ -166,18 +314,79 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   UserSample dco_decode_user_sample(dynamic raw);
 
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  ViewHistoryDto dco_decode_view_history_dto(dynamic raw);
+
   @protected
   Workspace dco_decode_workspace(dynamic raw);
 
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
+  @protected
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<AuthEvent> sse_decode_StreamSink_auth_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<IllustResponse> sse_decode_StreamSink_illust_response_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  AuthEvent sse_decode_auth_event(SseDeserializer deserializer);
+
+  @protected
+  AuthEventKind sse_decode_auth_event_kind(SseDeserializer deserializer);
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  ConnectionProfile sse_decode_box_autoadd_connection_profile(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DohSettings sse_decode_box_autoadd_doh_settings(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  Illust sse_decode_box_autoadd_illust(SseDeserializer deserializer);
+
+  @protected
+  IllustFilter sse_decode_box_autoadd_illust_filter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  IllustResponse sse_decode_box_autoadd_illust_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ImagePlaceholderDto sse_decode_box_autoadd_image_placeholder_dto(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProxySettings sse_decode_box_autoadd_proxy_settings(
+    SseDeserializer deserializer,
+  );
+
   @protected
   Series sse_decode_box_autoadd_series(SseDeserializer deserializer);
 
@@ -201,6 +410,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  BypassSniIpStatus sse_decode_bypass_sni_ip_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ConnectionProfile sse_decode_connection_profile(SseDeserializer deserializer);
+
+  @protected
+  DohSettings sse_decode_doh_settings(SseDeserializer deserializer);
+
   @protected
   DownloadTaskDto sse_decode_download_task_dto(SseDeserializer deserializer);
 
@@ -213,6 +433,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Illust sse_decode_illust(SseDeserializer deserializer);
 
+  @protected
+  IllustFilter sse_decode_illust_filter(SseDeserializer deserializer);
+
   @protected
   IllustResponse sse_decode_illust_response(SseDeserializer deserializer);
 
@@ -221,9 +444,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ImagePlaceholderDto sse_decode_image_placeholder_dto(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<BypassSniIpStatus> sse_decode_list_bypass_sni_ip_status(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<DownloadTaskDto> sse_decode_list_download_task_dto(
     SseDeserializer deserializer,
@@ -235,18 +468,65 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<MetaPage> sse_decode_list_meta_page(SseDeserializer deserializer);
 
+  @protected
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<ProxyTestResult> sse_decode_list_proxy_test_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RankMode> sse_decode_list_rank_mode(SseDeserializer deserializer);
+
+  @protected
+  List<RankingEntryDto> sse_decode_list_ranking_entry_dto(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RankingMoveDto> sse_decode_list_ranking_move_dto(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RateLimitState> sse_decode_list_rate_limit_state(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SimilarImageDto> sse_decode_list_similar_image_dto(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<Tag> sse_decode_list_tag(SseDeserializer deserializer);
 
   @protected
   List<TrendTag> sse_decode_list_trend_tag(SseDeserializer deserializer);
 
+  @protected
+  List<UiAccount> sse_decode_list_ui_account(SseDeserializer deserializer);
+
   @protected
   List<UserPreview> sse_decode_list_user_preview(SseDeserializer deserializer);
 
+  @protected
+  List<ViewHistoryDto> sse_decode_list_view_history_dto(
+    SseDeserializer deserializer,
+  );
+
   @protected
   LoginUrl sse_decode_login_url(SseDeserializer deserializer);
 
@@ -267,6 +547,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  IllustResponse? sse_decode_opt_box_autoadd_illust_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ImagePlaceholderDto? sse_decode_opt_box_autoadd_image_placeholder_dto(
+    SseDeserializer deserializer,
+  );
+
   @protected
   Series? sse_decode_opt_box_autoadd_series(SseDeserializer deserializer);
 
@@ -284,18 +577,65 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ProfilePublicity sse_decode_profile_publicity(SseDeserializer deserializer);
 
+  @protected
+  ProxySettings sse_decode_proxy_settings(SseDeserializer deserializer);
+
+  @protected
+  ProxyTestResult sse_decode_proxy_test_result(SseDeserializer deserializer);
+
+  @protected
+  RankMode sse_decode_rank_mode(SseDeserializer deserializer);
+
+  @protected
+  RankingDiff sse_decode_ranking_diff(SseDeserializer deserializer);
+
+  @protected
+  RankingEntryDto sse_decode_ranking_entry_dto(SseDeserializer deserializer);
+
+  @protected
+  RankingMoveDto sse_decode_ranking_move_dto(SseDeserializer deserializer);
+
+  @protected
+  RateLimitState sse_decode_rate_limit_state(SseDeserializer deserializer);
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Restrict sse_decode_restrict(SseDeserializer deserializer);
+
+  @protected
+  SearchSort sse_decode_search_sort(SseDeserializer deserializer);
+
+  @protected
+  SearchTarget sse_decode_search_target(SseDeserializer deserializer);
+
   @protected
   Series sse_decode_series(SseDeserializer deserializer);
 
+  @protected
+  SimilarImageDto sse_decode_similar_image_dto(SseDeserializer deserializer);
+
   @protected
   Tag sse_decode_tag(SseDeserializer deserializer);
 
   @protected
   TrendTag sse_decode_trend_tag(SseDeserializer deserializer);
 
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer);
 
+  @protected
+  UiAccount sse_decode_ui_account(SseDeserializer deserializer);
+
   @protected
   UiCurrentUser sse_decode_ui_current_user(SseDeserializer deserializer);
 
@@ -334,6 +674,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   UserSample sse_decode_user_sample(SseDeserializer deserializer);
 
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  ViewHistoryDto sse_decode_view_history_dto(SseDeserializer deserializer);
+
   @protected
   Workspace sse_decode_workspace(SseDeserializer deserializer);
 
@@ -343,12 +689,81 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_Map_String_String_None(
+    Map<String, String> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_auth_event_Sse(
+    RustStreamSink<AuthEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_illust_response_Sse(
+    RustStreamSink<IllustResponse> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_auth_event(AuthEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_auth_event_kind(AuthEventKind self, SseSerializer serializer);
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_connection_profile(
+    ConnectionProfile self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_doh_settings(
+    DohSettings self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_illust(Illust self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_illust_filter(
+    IllustFilter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_illust_response(
+    IllustResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_image_placeholder_dto(
+    ImagePlaceholderDto self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_proxy_settings(
+    ProxySettings self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_series(Series self, SseSerializer serializer);
 
@@ -376,6 +791,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_bypass_sni_ip_status(
+    BypassSniIpStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_connection_profile(
+    ConnectionProfile self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_doh_settings(DohSettings self, SseSerializer serializer);
+
   @protected
   void sse_encode_download_task_dto(
     DownloadTaskDto self,
@@ -391,6 +821,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_illust(Illust self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_illust_filter(IllustFilter self, SseSerializer serializer);
+
   @protected
   void sse_encode_illust_response(
     IllustResponse self,
@@ -403,9 +836,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_image_placeholder_dto(
+    ImagePlaceholderDto self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_bypass_sni_ip_status(
+    List<BypassSniIpStatus> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_download_task_dto(
     List<DownloadTaskDto> self,
@@ -418,24 +863,87 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_meta_page(List<MetaPage> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_prim_i_64_strict(
+    Int64List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_proxy_test_result(
+    List<ProxyTestResult> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_rank_mode(List<RankMode> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_ranking_entry_dto(
+    List<RankingEntryDto> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_ranking_move_dto(
+    List<RankingMoveDto> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_rate_limit_state(
+    List<RateLimitState> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_similar_image_dto(
+    List<SimilarImageDto> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_tag(List<Tag> self, SseSerializer serializer);
 
   @protected
   void sse_encode_list_trend_tag(List<TrendTag> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_ui_account(
+    List<UiAccount> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_user_preview(
     List<UserPreview> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_view_history_dto(
+    List<ViewHistoryDto> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_login_url(LoginUrl self, SseSerializer serializer);
 
@@ -460,6 +968,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_illust_response(
+    IllustResponse? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_image_placeholder_dto(
+    ImagePlaceholderDto? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_series(
     Series? self,
@@ -487,18 +1013,81 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_proxy_settings(ProxySettings self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_proxy_test_result(
+    ProxyTestResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_rank_mode(RankMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_ranking_diff(RankingDiff self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_ranking_entry_dto(
+    RankingEntryDto self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_ranking_move_dto(
+    RankingMoveDto self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_rate_limit_state(
+    RateLimitState self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_record_string_string(
+    (String, String) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_restrict(Restrict self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_sort(SearchSort self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_target(SearchTarget self, SseSerializer serializer);
+
   @protected
   void sse_encode_series(Series self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_similar_image_dto(
+    SimilarImageDto self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_tag(Tag self, SseSerializer serializer);
 
   @protected
   void sse_encode_trend_tag(TrendTag self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_ui_account(UiAccount self, SseSerializer serializer);
+
   @protected
   void sse_encode_ui_current_user(UiCurrentUser self, SseSerializer serializer);
 
@@ -541,6 +1130,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_user_sample(UserSample self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_view_history_dto(
+    ViewHistoryDto self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_workspace(Workspace self, SseSerializer serializer);
 }
@@ -30,6 +30,7 @@ class Illust {
   final bool isBookmarked;
   final bool visible;
   final bool isMuted;
+  final PlatformInt64 illustAiType;
 
   const Illust({
     required this.id,
@@ -55,6 +56,7 @@ class Illust {
     required this.isBookmarked,
     required this.visible,
     required this.isMuted,
+    required this.illustAiType,
   });
 
   @override
@@ -81,7 +83,8 @@ class Illust {
       totalBookmarks.hashCode ^
       isBookmarked.hashCode ^
       visible.hashCode ^
-      isMuted.hashCode;
+      isMuted.hashCode ^
+      illustAiType.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -110,17 +113,29 @@ class Illust {
           totalBookmarks == other.totalBookmarks &&
           isBookmarked == other.isBookmarked &&
           visible == other.visible &&
-          isMuted == other.isMuted;
+          isMuted == other.isMuted &&
+          illustAiType == other.illustAiType;
 }
 
 class IllustResponse {
   final List<Illust> illusts;
   final String? nextUrl;
+  final PlatformInt64 hiddenCount;
+  final bool stale;
 
-  const IllustResponse({required this.illusts, this.nextUrl});
+  const IllustResponse({
+    required this.illusts,
+    this.nextUrl,
+    required this.hiddenCount,
+    required this.stale,
+  });
 
   @override
-  int get hashCode => illusts.hashCode ^ nextUrl.hashCode;
+  int get hashCode =>
+      illusts.hashCode ^
+      nextUrl.hashCode ^
+      hiddenCount.hashCode ^
+      stale.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -128,7 +143,9 @@ class IllustResponse {
       other is IllustResponse &&
           runtimeType == other.runtimeType &&
           illusts == other.illusts &&
-          nextUrl == other.nextUrl;
+          nextUrl == other.nextUrl &&
+          hiddenCount == other.hiddenCount &&
+          stale == other.stale;
 }
 
 class IllustTrendingTags {
@@ -418,6 +435,60 @@ class ProfilePublicity {
           region == other.region;
 }
 
+/// 排行榜类型, r18 / r18g 需要账号开启对应的浏览设置
+enum RankMode {
+  day,
+  week,
+  month,
+  dayMale,
+  dayFemale,
+  weekOriginal,
+  weekRookie,
+  dayAi,
+  dayManga,
+  weekManga,
+  monthManga,
+  weekRookieManga,
+  dayR18,
+  dayMaleR18,
+  dayFemaleR18,
+  weekR18,
+  weekR18G,
+  dayR18Ai,
+  dayR18Manga,
+  weekR18Manga,
+  weekR18GManga,
+  ;
+}
+
+/// 收藏 / 关注的公开范围
+enum Restrict {
+  public,
+  private,
+  ;
+}
+
+/// 搜索排序, 热门排序只有会员可以使用
+enum SearchSort {
+  dateDesc,
+  dateAsc,
+  popularDesc,
+  popularMaleDesc,
+  popularFemaleDesc,
+  ;
+}
+
+/// 搜索范围
+enum SearchTarget {
+  /// 标签部分一致
+  partialMatchForTags,
+  /// 标签完全一致
+  exactMatchForTags,
+  /// 标题说明文
+  titleAndCaption,
+  ;
+}
+
 class Series {
   final PlatformInt64 id;
   final String title;
@@ -526,12 +597,14 @@ class UserDetail {
   final ProfilePublicity profilePublicity;
   final User user;
   final Workspace workspace;
+  final bool stale;
 
   const UserDetail({
     required this.profile,
     required this.profilePublicity,
     required this.user,
     required this.workspace,
+    required this.stale,
   });
 
   @override
@@ -539,7 +612,8 @@ class UserDetail {
       profile.hashCode ^
       profilePublicity.hashCode ^
       user.hashCode ^
-      workspace.hashCode;
+      workspace.hashCode ^
+      stale.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -549,7 +623,8 @@ class UserDetail {
           profile == other.profile &&
           profilePublicity == other.profilePublicity &&
           user == other.user &&
-          workspace == other.workspace;
+          workspace == other.workspace &&
+          stale == other.stale;
 }
 
 class UserPreview {
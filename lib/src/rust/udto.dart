@@ -5,6 +5,176 @@
 
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'pixirust/entities.dart';
+
+/// 登录状态变化, 通过 auth_event_stream 推送给UI
+class AuthEvent {
+  final AuthEventKind kind;
+  /// RefreshFailed / AccountsUnreadable 时的原因, 其他情况为空
+  final String reason;
+
+  const AuthEvent({required this.kind, required this.reason});
+
+  @override
+  int get hashCode => kind.hashCode ^ reason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AuthEvent &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          reason == other.reason;
+}
+
+enum AuthEventKind {
+  loggedIn,
+  tokenRefreshed,
+  /// OAuth 服务拒绝了刷新(invalid_grant 等4xx), 例如 refresh token 已在服务端被吊销;
+  /// 网络错误和5xx只返回错误, 不推送该事件
+  refreshFailed,
+  loggedOut,
+  /// 保存的账号无法解密(密钥文件丢失或损坏), 原数据已另存, 需要重新登录
+  accountsUnreadable,
+  ;
+}
+
+/// SNI bypass 候选IP的状态
+class BypassSniIpStatus {
+  final String host;
+  final String ip;
+  /// 当前请求优先使用的IP
+  final bool selected;
+  final bool checked;
+  final bool healthy;
+  /// 失败时为 -1
+  final PlatformInt64 tcpMs;
+  final PlatformInt64 tlsMs;
+  final String error;
+  final PlatformInt64 checkedTime;
+
+  const BypassSniIpStatus({
+    required this.host,
+    required this.ip,
+    required this.selected,
+    required this.checked,
+    required this.healthy,
+    required this.tcpMs,
+    required this.tlsMs,
+    required this.error,
+    required this.checkedTime,
+  });
+
+  @override
+  int get hashCode =>
+      host.hashCode ^
+      ip.hashCode ^
+      selected.hashCode ^
+      checked.hashCode ^
+      healthy.hashCode ^
+      tcpMs.hashCode ^
+      tlsMs.hashCode ^
+      error.hashCode ^
+      checkedTime.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BypassSniIpStatus &&
+          runtimeType == other.runtimeType &&
+          host == other.host &&
+          ip == other.ip &&
+          selected == other.selected &&
+          checked == other.checked &&
+          healthy == other.healthy &&
+          tcpMs == other.tcpMs &&
+          tlsMs == other.tlsMs &&
+          error == other.error &&
+          checkedTime == other.checkedTime;
+}
+
+/// 网络连接设置, 同时用于普通请求和 SNI bypass
+class ConnectionProfile {
+  final BigInt connectTimeoutSecs;
+  /// 两次读取之间的最长等待
+  final BigInt readTimeoutSecs;
+  /// 整个请求的超时, 0 表示不限制(下载大图时可能很久)
+  final BigInt totalTimeoutSecs;
+  final BigInt poolMaxIdlePerHost;
+  final BigInt poolIdleTimeoutSecs;
+  /// 0 表示不开启
+  final BigInt tcpKeepaliveSecs;
+  /// 通过 ALPN 协商 HTTP/2
+  final bool http2;
+  /// gzip / brotli
+  final bool compression;
+
+  const ConnectionProfile({
+    required this.connectTimeoutSecs,
+    required this.readTimeoutSecs,
+    required this.totalTimeoutSecs,
+    required this.poolMaxIdlePerHost,
+    required this.poolIdleTimeoutSecs,
+    required this.tcpKeepaliveSecs,
+    required this.http2,
+    required this.compression,
+  });
+
+  @override
+  int get hashCode =>
+      connectTimeoutSecs.hashCode ^
+      readTimeoutSecs.hashCode ^
+      totalTimeoutSecs.hashCode ^
+      poolMaxIdlePerHost.hashCode ^
+      poolIdleTimeoutSecs.hashCode ^
+      tcpKeepaliveSecs.hashCode ^
+      http2.hashCode ^
+      compression.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConnectionProfile &&
+          runtimeType == other.runtimeType &&
+          connectTimeoutSecs == other.connectTimeoutSecs &&
+          readTimeoutSecs == other.readTimeoutSecs &&
+          totalTimeoutSecs == other.totalTimeoutSecs &&
+          poolMaxIdlePerHost == other.poolMaxIdlePerHost &&
+          poolIdleTimeoutSecs == other.poolIdleTimeoutSecs &&
+          tcpKeepaliveSecs == other.tcpKeepaliveSecs &&
+          http2 == other.http2 &&
+          compression == other.compression;
+}
+
+/// DNS-over-HTTPS 设置
+class DohSettings {
+  final bool enabled;
+  /// JSON 格式的 DoH 地址, 按顺序尝试
+  final List<String> endpoints;
+  /// 使用 DoH 的结果更新 SNI bypass 的IP
+  final bool updateBypassHosts;
+
+  const DohSettings({
+    required this.enabled,
+    required this.endpoints,
+    required this.updateBypassHosts,
+  });
+
+  @override
+  int get hashCode =>
+      enabled.hashCode ^
+      endpoints.hashCode ^
+      updateBypassHosts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DohSettings &&
+          runtimeType == other.runtimeType &&
+          enabled == other.enabled &&
+          endpoints == other.endpoints &&
+          updateBypassHosts == other.updateBypassHosts;
+}
 
 class DownloadTaskDto {
   final PlatformInt64 id;
@@ -77,6 +247,361 @@ class DownloadTaskDto {
           updatedTime == other.updatedTime;
 }
 
+class IllustFilter {
+  final List<String> mutedTags;
+  final Int64List mutedUserIds;
+  final bool hideAi;
+  final bool hideR18;
+  final bool hideUgoira;
+
+  const IllustFilter({
+    required this.mutedTags,
+    required this.mutedUserIds,
+    required this.hideAi,
+    required this.hideR18,
+    required this.hideUgoira,
+  });
+
+  @override
+  int get hashCode =>
+      mutedTags.hashCode ^
+      mutedUserIds.hashCode ^
+      hideAi.hashCode ^
+      hideR18.hashCode ^
+      hideUgoira.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IllustFilter &&
+          runtimeType == other.runtimeType &&
+          mutedTags == other.mutedTags &&
+          mutedUserIds == other.mutedUserIds &&
+          hideAi == other.hideAi &&
+          hideR18 == other.hideR18 &&
+          hideUgoira == other.hideUgoira;
+}
+
+class ImagePlaceholderDto {
+  final String url;
+  final String blurhash;
+  final Uint32List palette;
+  final int width;
+  final int height;
+
+  const ImagePlaceholderDto({
+    required this.url,
+    required this.blurhash,
+    required this.palette,
+    required this.width,
+    required this.height,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      blurhash.hashCode ^
+      palette.hashCode ^
+      width.hashCode ^
+      height.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImagePlaceholderDto &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          blurhash == other.blurhash &&
+          palette == other.palette &&
+          width == other.width &&
+          height == other.height;
+}
+
+/// 代理设置, url 为空表示不使用代理
+class ProxySettings {
+  /// http / https / socks5 / socks5h://host:port
+  final String url;
+  final String username;
+  final String password;
+  /// 不走代理的地址, 逗号分隔, 例如 "localhost,127.0.0.1,.example.com"
+  final String noProxy;
+
+  const ProxySettings({
+    required this.url,
+    required this.username,
+    required this.password,
+    required this.noProxy,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      username.hashCode ^
+      password.hashCode ^
+      noProxy.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProxySettings &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          username == other.username &&
+          password == other.password &&
+          noProxy == other.noProxy;
+}
+
+class ProxyTestResult {
+  final String url;
+  /// 请求失败时为 -1
+  final PlatformInt64 latencyMs;
+  final int status;
+  final String error;
+
+  const ProxyTestResult({
+    required this.url,
+    required this.latencyMs,
+    required this.status,
+    required this.error,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      latencyMs.hashCode ^
+      status.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProxyTestResult &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          latencyMs == other.latencyMs &&
+          status == other.status &&
+          error == other.error;
+}
+
+class RankingDiff {
+  final RankMode mode;
+  final String fromDate;
+  final String toDate;
+  /// 新上榜, 按名次排序
+  final List<RankingMoveDto> newEntries;
+  /// 名次上升, 上升多的在前
+  final List<RankingMoveDto> climbed;
+  /// 名次下降, 下降多的在前
+  final List<RankingMoveDto> fell;
+  /// 落榜, 按原名次排序
+  final List<RankingMoveDto> dropped;
+
+  const RankingDiff({
+    required this.mode,
+    required this.fromDate,
+    required this.toDate,
+    required this.newEntries,
+    required this.climbed,
+    required this.fell,
+    required this.dropped,
+  });
+
+  @override
+  int get hashCode =>
+      mode.hashCode ^
+      fromDate.hashCode ^
+      toDate.hashCode ^
+      newEntries.hashCode ^
+      climbed.hashCode ^
+      fell.hashCode ^
+      dropped.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RankingDiff &&
+          runtimeType == other.runtimeType &&
+          mode == other.mode &&
+          fromDate == other.fromDate &&
+          toDate == other.toDate &&
+          newEntries == other.newEntries &&
+          climbed == other.climbed &&
+          fell == other.fell &&
+          dropped == other.dropped;
+}
+
+/// 排行榜快照中的一条记录
+class RankingEntryDto {
+  final RankMode mode;
+  final String date;
+  /// 从1开始
+  final PlatformInt64 rank;
+  final Illust illust;
+  final PlatformInt64 capturedTime;
+
+  const RankingEntryDto({
+    required this.mode,
+    required this.date,
+    required this.rank,
+    required this.illust,
+    required this.capturedTime,
+  });
+
+  @override
+  int get hashCode =>
+      mode.hashCode ^
+      date.hashCode ^
+      rank.hashCode ^
+      illust.hashCode ^
+      capturedTime.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RankingEntryDto &&
+          runtimeType == other.runtimeType &&
+          mode == other.mode &&
+          date == other.date &&
+          rank == other.rank &&
+          illust == other.illust &&
+          capturedTime == other.capturedTime;
+}
+
+/// 作品在两天排行榜之间的变化, 不在榜上时名次为 None
+class RankingMoveDto {
+  final Illust illust;
+  final PlatformInt64? fromRank;
+  final PlatformInt64? toRank;
+
+  const RankingMoveDto({required this.illust, this.fromRank, this.toRank});
+
+  @override
+  int get hashCode => illust.hashCode ^ fromRank.hashCode ^ toRank.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RankingMoveDto &&
+          runtimeType == other.runtimeType &&
+          illust == other.illust &&
+          fromRank == other.fromRank &&
+          toRank == other.toRank;
+}
+
+/// 某个host的限流状态
+class RateLimitState {
+  final String host;
+  /// 当前可立即发出的请求数
+  final PlatformInt64 available;
+  final PlatformInt64 capacity;
+  /// 正在排队等待的请求数
+  final PlatformInt64 waiting;
+  /// 收到 429 后剩余的暂停时间
+  final PlatformInt64 pausedMs;
+  /// 累计收到 429 的次数
+  final PlatformInt64 throttledCount;
+
+  const RateLimitState({
+    required this.host,
+    required this.available,
+    required this.capacity,
+    required this.waiting,
+    required this.pausedMs,
+    required this.throttledCount,
+  });
+
+  @override
+  int get hashCode =>
+      host.hashCode ^
+      available.hashCode ^
+      capacity.hashCode ^
+      waiting.hashCode ^
+      pausedMs.hashCode ^
+      throttledCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RateLimitState &&
+          runtimeType == other.runtimeType &&
+          host == other.host &&
+          available == other.available &&
+          capacity == other.capacity &&
+          waiting == other.waiting &&
+          pausedMs == other.pausedMs &&
+          throttledCount == other.throttledCount;
+}
+
+class SimilarImageDto {
+  final String url;
+  final String path;
+  final int distance;
+  final bool downloaded;
+
+  const SimilarImageDto({
+    required this.url,
+    required this.path,
+    required this.distance,
+    required this.downloaded,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      path.hashCode ^
+      distance.hashCode ^
+      downloaded.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SimilarImageDto &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          path == other.path &&
+          distance == other.distance &&
+          downloaded == other.downloaded;
+}
+
+class UiAccount {
+  final PlatformInt64 userId;
+  final String name;
+  final String account;
+  final String profileImageUrl;
+  final bool isPremium;
+  final bool isCurrent;
+
+  const UiAccount({
+    required this.userId,
+    required this.name,
+    required this.account,
+    required this.profileImageUrl,
+    required this.isPremium,
+    required this.isCurrent,
+  });
+
+  @override
+  int get hashCode =>
+      userId.hashCode ^
+      name.hashCode ^
+      account.hashCode ^
+      profileImageUrl.hashCode ^
+      isPremium.hashCode ^
+      isCurrent.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UiAccount &&
+          runtimeType == other.runtimeType &&
+          userId == other.userId &&
+          name == other.name &&
+          account == other.account &&
+          profileImageUrl == other.profileImageUrl &&
+          isPremium == other.isPremium &&
+          isCurrent == other.isCurrent;
+}
+
 class UiCurrentUser {
   final PlatformInt64 userId;
   final String name;
@@ -113,7 +638,7 @@ class UiCurrentUser {
 }
 
 class UiIllustRankQuery {
-  final String mode;
+  final RankMode mode;
   final String date;
 
   const UiIllustRankQuery({required this.mode, required this.date});
@@ -132,8 +657,8 @@ class UiIllustRankQuery {
 
 class UiIllustSearchQuery {
   final String word;
-  final String searchTarget;
-  final String sort;
+  final SearchTarget searchTarget;
+  final SearchSort sort;
 
   const UiIllustSearchQuery({
     required this.word,
@@ -171,3 +696,34 @@ class UiLoginByCodeQuery {
           code == other.code &&
           verify == other.verify;
 }
+
+class ViewHistoryDto {
+  final Illust illust;
+  final int viewCount;
+  final PlatformInt64 firstViewTime;
+  final PlatformInt64 lastViewTime;
+
+  const ViewHistoryDto({
+    required this.illust,
+    required this.viewCount,
+    required this.firstViewTime,
+    required this.lastViewTime,
+  });
+
+  @override
+  int get hashCode =>
+      illust.hashCode ^
+      viewCount.hashCode ^
+      firstViewTime.hashCode ^
+      lastViewTime.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ViewHistoryDto &&
+          runtimeType == other.runtimeType &&
+          illust == other.illust &&
+          viewCount == other.viewCount &&
+          firstViewTime == other.firstViewTime &&
+          lastViewTime == other.lastViewTime;
+}
@@ -1,14 +1,30 @@
-use crate::entities::{network_image, property, download_task};
+use crate::entities::{
+    download_task, image_hash, image_placeholder, network_image, property, view_history,
+};
 use crate::local::{
-    client, get_in_china_, hash_lock, init_bypass_sni_settings, join_paths,
-    load_in_china, load_token, set_bypass_sni_cache, set_bypass_sni_hosts_cache, set_in_china_,
-    set_token,
+    Cancelled, apply_network_settings, bypass_sni_diagnostics_, cached_get, cached_get_text,
+    cancel_, cancellable, capture_pending_rankings, capture_ranking_, clear_ranking_snapshots_,
+    clear_response_cache_, client, current_account_id, cursor_close_, cursor_collect_all_,
+    cursor_next_, cursor_open_, cursor_stream_, evict_bookmark_cache, evict_follow_cache,
+    filter_illust_response, get_connection_profile_, get_doh_settings_, get_illust_filter_,
+    get_in_china_, get_proxy_settings_, get_ranking_capture_modes_, get_response_cache_offline_,
+    get_server_urls_, hash_lock, init_bypass_sni_settings, is_server_property, join_paths,
+    latest_ranking_date, list_accounts_, load_illust_page, load_in_china, load_server_urls,
+    load_token, logout_, ranking_diff_, ranking_history_of_user_, ranking_snapshot_,
+    ranking_snapshot_dates_, rate_limit_states_, refresh_bypass_sni_hosts_, remove_account_,
+    set_auth_event_sink, set_bypass_sni_cache, set_bypass_sni_hosts_cache, set_connection_profile_,
+    set_doh_settings_, set_illust_filter_, set_in_china_, set_proxy_settings_,
+    set_ranking_capture_modes_, set_response_cache_offline_, set_server_urls_, set_token,
+    start_bypass_sni_probe_loop, start_ranking_capture_loop, switch_account_, test_proxy_,
+    with_auth_retry,
 };
-use crate::pixirust::client::{IllustTrendingTags, UserDetail};
-use crate::pixirust::entities::{IllustResponse, UserPreviewsResponse};
+use crate::frb_generated::StreamSink;
+use crate::pixirust::client::{IllustTrendingTags, ServerUrls, UserDetail};
+use crate::pixirust::entities::{Illust, IllustResponse, RankMode, Restrict, UserPreviewsResponse};
 use crate::pixirust::entities::LoginUrl;
 use crate::udto::*;
 use crate::get_network_image_dir;
+use crate::imaging::{dhash, hamming_distance, placeholder, resize_image, ResizedFormat};
 use anyhow::{Context, Ok, Result};
 use std::collections::HashMap;
 use std::future::Future;
@@ -59,32 +75,39 @@ pub fn init(root: String) -> Result<()> {
     block_on(async {
         load_in_china().await;
         init_bypass_sni_settings().await;
+        start_bypass_sni_probe_loop();
+        start_ranking_capture_loop();
+        load_server_urls().await;
+        // 网络设置有误时使用默认设置, 避免无法启动
+        if let Err(err) = apply_network_settings().await {
+            println!("Failed to apply network settings : {}", err);
+        }
     });
     Ok(())
 }
 
-pub fn save_property(k: String, v: String) -> Result<()> {
-    block_on(async move {
-        let key = k.clone();
-        let value = v.clone();
-        property::save_property(k, v).await?;
-        if key == "bypass_sni" {
-            let vv = value.trim().to_lowercase();
-            let parsed = vv == "true" || vv == "1" || vv == "yes";
-            set_bypass_sni_cache(parsed).await;
-        } else if key == "bypass_sni_hosts" {
-            if let std::result::Result::Ok(map) =
-                serde_json::from_str::<HashMap<String, String>>(&value)
-            {
-                set_bypass_sni_hosts_cache(map).await;
-            }
-        }        
-        Ok(())
-    })    
+pub async fn save_property(k: String, v: String) -> Result<()> {
+    let key = k.clone();
+    let value = v.clone();
+    property::save_property(k, v).await?;
+    if key == "bypass_sni" {
+        let vv = value.trim().to_lowercase();
+        let parsed = vv == "true" || vv == "1" || vv == "yes";
+        set_bypass_sni_cache(parsed).await;
+    } else if key == "bypass_sni_hosts" {
+        if let std::result::Result::Ok(map) =
+            serde_json::from_str::<HashMap<String, String>>(&value)
+        {
+            set_bypass_sni_hosts_cache(map).await;
+        }
+    } else if is_server_property(&key) {
+        load_server_urls().await;
+    }
+    Ok(())
 }
 
-pub fn load_property(k: String) -> Result<String> {
-    block_on(async move { Ok(property::load_property(k).await?) })
+pub async fn load_property(k: String) -> Result<String> {
+    Ok(property::load_property(k).await?)
 }
 
 fn block_on<T>(f: impl Future<Output = T>) -> T {
@@ -109,179 +132,453 @@ pub fn copy_image_to(src_path: String, to_dir: String) -> Result<()> {
     Ok(())
 }
 
-pub fn set_in_china(value: bool) {
-    block_on(set_in_china_(value))
+/// 修改并保存 app-api / oauth / 图片服务的地址, 空值表示使用pixiv官方地址
+pub async fn set_server_urls(app: String, oauth: String, image: String) -> Result<()> {
+    set_server_urls_(ServerUrls { app, oauth, image }).await
+}
+
+pub async fn get_proxy_settings() -> ProxySettings {
+    get_proxy_settings_().await
+}
+
+/// 保存代理设置并立即重建网络客户端
+pub async fn set_proxy_settings(settings: ProxySettings) -> Result<()> {
+    set_proxy_settings_(settings).await
+}
+
+pub async fn get_doh_settings() -> DohSettings {
+    get_doh_settings_().await
+}
+
+/// 保存 DoH 设置并立即重建网络客户端
+pub async fn set_doh_settings(settings: DohSettings) -> Result<()> {
+    set_doh_settings_(settings).await
+}
+
+/// 使用 DoH 重新解析并保存 SNI bypass 的IP, 返回新的 host -> ip
+pub async fn refresh_bypass_sni_hosts() -> Result<HashMap<String, String>> {
+    refresh_bypass_sni_hosts_().await
+}
+
+pub async fn get_connection_profile() -> ConnectionProfile {
+    get_connection_profile_().await
+}
+
+/// 保存连接设置(超时 / 连接池 / HTTP2 / 压缩)并立即重建网络客户端
+pub async fn set_connection_profile(profile: ConnectionProfile) -> Result<()> {
+    set_connection_profile_(profile).await
+}
+
+/// 立即探测 SNI bypass 的所有候选IP, 返回每个IP的状态
+pub async fn bypass_sni_diagnostics() -> Result<Vec<BypassSniIpStatus>> {
+    bypass_sni_diagnostics_().await
+}
+
+/// 各host的限流状态, 用于显示下载变慢的原因
+pub async fn rate_limit_states() -> Vec<RateLimitState> {
+    rate_limit_states_().await
+}
+
+/// 测试当前网络设置下到各 pixiv 服务的延迟
+pub async fn test_proxy() -> Vec<ProxyTestResult> {
+    test_proxy_().await
+}
+
+/// 当前使用的服务地址, 依次为 app-api / oauth / 图片
+pub async fn get_server_urls() -> Vec<String> {
+    let servers = get_server_urls_().await;
+    vec![servers.app, servers.oauth, servers.image]
 }
 
-pub fn get_in_china() -> bool {
-    block_on(get_in_china_())
+pub async fn set_in_china(value: bool) {
+    set_in_china_(value).await
 }
 
-pub fn per_in_china() {
+pub async fn get_in_china() -> bool {
+    get_in_china_().await
+}
+
+pub async fn per_in_china() {
     println!("I AM IN RUST");
-    block_on(async {
-        load_in_china().await;
-    });
+    load_in_china().await;
 }
 
-pub fn pre_login() -> Result<bool> {
-    block_on(async { load_token().await })
+pub async fn pre_login() -> Result<bool> {
+    load_token().await
 }
 
-pub fn create_login_url() -> LoginUrl {
-    block_on(async {
-        let raw = crate::local::client(-1).await.unwrap().create_login_url();
-        LoginUrl {
-            verify: raw.verify,
-            url: raw.url,
-        }
-    })
+pub async fn create_login_url() -> LoginUrl {
+    let raw = crate::local::client(-1).await.unwrap().create_login_url();
+    LoginUrl {
+        verify: raw.verify,
+        url: raw.url,
+    }
 }
 
-pub fn login_by_code(query: UiLoginByCodeQuery) -> Result<bool> {
-    block_on(async {
-        let token = client(-1)
-            .await
-            .unwrap()
-            .load_token_by_code(query.code, query.verify)
-            .await?;
-        set_token(token, chrono::Local::now().timestamp_millis()).await;
-        Ok(true)
-    })
+pub async fn login_by_code(query: UiLoginByCodeQuery) -> Result<bool> {
+    let token = client(-1)
+        .await
+        .unwrap()
+        .load_token_by_code(query.code, query.verify)
+        .await?;
+    set_token(token, chrono::Local::now().timestamp_millis()).await?;
+    Ok(true)
 }
 
-pub fn create_register_url() -> Result<LoginUrl> {
-    block_on(async { Ok(crate::local::client(1).await?.create_register_url()) })
+pub async fn create_register_url() -> Result<LoginUrl> {
+    Ok(crate::local::client(1).await?.create_register_url())
 }
 
-pub fn request_url(params: String) -> Result<String> {
-    block_on(async { client(2).await?.get_from_pixiv_raw(params).await })
+/// 请求 app-api, 网络失败时返回的过期缓存在顶层带有 "stale": true
+pub async fn request_url(params: String) -> Result<String> {
+    let (body, stale) = cached_get_text(2, params).await?;
+    if !stale {
+        return Ok(body);
+    }
+    let mut value: serde_json::Value = serde_json::from_str(&body)?;
+    if let Some(object) = value.as_object_mut() {
+        object.insert("stale".to_owned(), serde_json::Value::Bool(true));
+    }
+    Ok(serde_json::to_string(&value)?)
 }
 
-pub fn illust_from_url(url: String) -> Result<IllustResponse> {
-    block_on(async {
-        let illust = client(2).await?.illust_from_url(url).await?;
-        Ok(illust.into())
-    })
+/// 加载作品列表, 返回前按本地屏蔽规则过滤（hidden_count 为被隐藏的数量）
+pub async fn illust_from_url(url: String) -> Result<IllustResponse> {
+    load_illust_page(url).await
 }
 
-pub fn user_previews_from_url(url: String) -> Result<UserPreviewsResponse> {
-    block_on(async {
-        let result = client(2).await?.user_previews_from_url(url).await?;
-        Ok(result.into())
-    })
+/// 同 illust_from_url, 可以通过 cancel(request_id) 取消
+pub async fn illust_from_url_cancellable(url: String, request_id: String) -> Result<IllustResponse> {
+    cancellable(&request_id, load_illust_page(url)).await
 }
 
-pub fn illust_recommended_first_url() -> Result<String> {
-    block_on(async {
-        Ok(crate::local::client(1)
-            .await?
-            .illust_recommended_first_url())
-    })
+/// 取消 request_id 对应的所有进行中的请求（例如离开页面时）, 返回是否有请求被取消
+pub fn cancel(request_id: String) -> bool {
+    cancel_(&request_id)
 }
 
-pub fn illust_search_first_url(query: UiIllustSearchQuery) -> Result<String> {
-    block_on(async {
-        let url = client(-1)
-            .await?
-            .illust_search_first_url(query.word, query.search_target, query.sort);
-        println!("Search URL: {}", url);
-        Ok(url)
-    })
+/// 本地屏蔽规则（屏蔽标签、屏蔽用户、隐藏AI/R-18/动图）
+pub async fn get_illust_filter() -> IllustFilter {
+    get_illust_filter_().await
 }
 
-pub fn illust_rank_first_url(query: UiIllustRankQuery) -> Result<String> {
-    block_on(async {
-        Ok(client(-1)
-            .await?
-            .illust_rank_first_url(query.mode, query.date))
-    })
+pub async fn set_illust_filter(filter: IllustFilter) -> Result<()> {
+    set_illust_filter_(filter).await
 }
 
-pub fn user_illusts_first_url(user_id: i64) -> Result<String> {
-    block_on(async {
-        Ok(client(-1).await?.user_illusts_first_url(user_id))
+pub async fn user_previews_from_url(url: String) -> Result<UserPreviewsResponse> {
+    let result = with_auth_retry(2, |client| {
+        let url = url.clone();
+        async move { client.user_previews_from_url(url).await }
     })
+    .await?;
+    Ok(result.into())
 }
 
-pub fn illust_trending_tags() -> Result<IllustTrendingTags> {
-    block_on(async { crate::local::client(1).await?.illust_trending_tags().await })
+pub async fn illust_recommended_first_url() -> Result<String> {
+    Ok(crate::local::client(1)
+        .await?
+        .illust_recommended_first_url())
 }
 
-pub fn illust_trending_tags_url() -> String {
-    block_on(async {
-        crate::local::client(-1)
-            .await
-            .unwrap()
-            .illust_trending_tags_url()
+/// 热门排序只有会员可以使用, 非会员时返回错误
+pub async fn illust_search_first_url(query: UiIllustSearchQuery) -> Result<String> {
+    if query.sort.is_premium_only() && !crate::local::TOKEN.lock().await.token.user.is_premium {
+        return Err(anyhow::Error::msg(format!(
+            "{} requires pixiv premium",
+            query.sort.as_str()
+        )));
+    }
+    let url = client(-1)
+        .await?
+        .illust_search_first_url(query.word, query.search_target, query.sort);
+    println!("Search URL: {}", url);
+    Ok(url)
+}
+
+pub async fn illust_rank_first_url(query: UiIllustRankQuery) -> Result<String> {
+    Ok(client(-1)
+        .await?
+        .illust_rank_first_url(query.mode, query.date))
+}
+
+pub async fn user_illusts_first_url(user_id: i64) -> Result<String> {
+    Ok(client(-1).await?.user_illusts_first_url(user_id))
+}
+
+pub async fn user_bookmarks_first_url(
+    user_id: i64,
+    restrict: Restrict,
+    tag: Option<String>,
+) -> Result<String> {
+    Ok(client(-1).await?.user_bookmarks_url(user_id, restrict, tag))
+}
+
+// ============= Feed Cursor =============
+
+/// 从任意列表的第一页url创建游标, 返回句柄, 用完后调用 cursor_close
+///
+/// 游标自己保存 next_url, 并去除不同页之间重复的作品
+pub fn cursor_open(first_url: String) -> i64 {
+    cursor_open_(first_url)
+}
+
+/// 读取下一页, 没有更多时返回 None
+pub async fn cursor_next(handle: i64) -> Result<Option<IllustResponse>> {
+    cursor_next_(handle).await
+}
+
+/// 以 stream 的形式依次返回剩余的页, 最多 max_pages 页, 需要更多时再次调用
+pub async fn cursor_stream(handle: i64, max_pages: i64, sink: StreamSink<IllustResponse>) -> Result<()> {
+    cursor_stream_(handle, max_pages, sink).await
+}
+
+/// 读取剩余的作品直到没有更多或达到 limit 个, 用于导出和批量任务; 最后一页超出 limit 的作品由下一次读取返回
+pub async fn cursor_collect_all(handle: i64, limit: i64) -> Result<Vec<Illust>> {
+    cursor_collect_all_(handle, limit).await
+}
+
+pub fn cursor_close(handle: i64) {
+    cursor_close_(handle)
+}
+
+pub async fn illust_trending_tags() -> Result<IllustTrendingTags> {
+    with_auth_retry(1, |client| async move {
+        client.illust_trending_tags().await
     })
+    .await
+}
+
+pub async fn illust_trending_tags_url() -> String {
+    crate::local::client(-1)
+        .await
+        .unwrap()
+        .illust_trending_tags_url()
 }
 
 /// 下载pixiv的图片
-pub fn load_pixiv_image(url: String) -> Result<String> {
-    block_on(async {
-        // hash锁
-        let _lock = hash_lock(&url).await;
-        // 查找图片是否有缓存
-        let db_image = network_image::find_by_url(url.clone()).await?;
-        let path = match db_image {
-            // 有缓存直接使用（如果文件仍存在）
-            Some(db_image) => {
-                let local = join_paths(vec![get_network_image_dir().as_str(), &db_image.path]);
-                if Path::new(&local).exists() {
-                    db_image.path
-                } else {
-                    // db 有记录但文件被清理/丢失，删除记录并重新下载
-                    let _ = network_image::delete_by_url(url.clone()).await;
-                    let now = chrono::Local::now().timestamp_millis();
-                    let client = crate::local::client(0).await?;
-                    let data: bytes::Bytes = client.load_image_data(url.clone()).await?;
-                    drop(client);
-                    let f = image::guess_format(data.as_ref())?;
-                    let ext = f.extensions_str()[0];
-                    let path = format!(
-                        "{}_{}.{}",
-                        hex::encode(md5::compute(url.clone()).to_vec()),
-                        &now,
-                        ext,
-                    );
-                    let local = join_paths(vec![get_network_image_dir().as_str(), &path]);
-                    std::fs::write(local, data).unwrap();
-                    network_image::insert(url.clone(), path.clone(), now.clone()).await?;
-                    path
-                }
-            }
-            // 没有缓存则下载
-            None => {
-                let now = chrono::Local::now().timestamp_millis();
-                let client = crate::local::client(0).await?;
-                let data: bytes::Bytes = client.load_image_data(url.clone()).await?;
-                drop(client);
-                let f = image::guess_format(data.as_ref())?;
-                let ext = f.extensions_str()[0];
-                let path = format!(
-                    "{}_{}.{}",
-                    hex::encode(md5::compute(url.clone()).to_vec()),
-                    &now,
-                    ext,
-                );
-                let local = join_paths(vec![get_network_image_dir().as_str(), &path]);
-                std::fs::write(local, data).unwrap();
-                network_image::insert(url.clone(), path.clone(), now.clone()).await?;
-                path
+pub async fn load_pixiv_image(url: String) -> Result<String> {
+    load_pixiv_image_(url).await
+}
+
+/// 同 load_pixiv_image, 可以通过 cancel(request_id) 取消
+pub async fn load_pixiv_image_cancellable(url: String, request_id: String) -> Result<String> {
+    cancellable(&request_id, load_pixiv_image_(url)).await
+}
+
+async fn load_pixiv_image_(url: String) -> Result<String> {
+    // hash锁
+    let _lock = hash_lock(&url).await;
+    // 查找图片是否有缓存
+    let db_image = network_image::find_by_url(url.clone()).await?;
+    let path = match db_image {
+        // 有缓存直接使用（如果文件仍存在）
+        Some(db_image) => {
+            let local = join_paths(vec![get_network_image_dir().as_str(), &db_image.path]);
+            if tokio::fs::try_exists(&local).await.unwrap_or(false) {
+                db_image.path
+            } else {
+                // db 有记录但文件被清理/丢失，删除记录并重新下载
+                let _ = network_image::delete_by_url(url.clone()).await;
+                download_image_to_cache(&url).await?
             }
+        }
+        // 没有缓存则下载
+        None => download_image_to_cache(&url).await?,
+    };
+    Ok(join_paths(vec![get_network_image_dir().as_str(), &path]))
+}
+
+/// 下载图片写入缓存目录并记录索引, 返回相对于缓存目录的路径
+async fn download_image_to_cache(url: &str) -> Result<String> {
+    let now = chrono::Local::now().timestamp_millis();
+    let client = crate::local::client(0).await?;
+    let data: bytes::Bytes = client.load_image_data(url.to_owned()).await?;
+    drop(client);
+    let f = image::guess_format(data.as_ref())?;
+    let ext = f.extensions_str()[0];
+    let path = format!(
+        "{}_{}.{}",
+        hex::encode(md5::compute(url).to_vec()),
+        &now,
+        ext,
+    );
+    let local = join_paths(vec![get_network_image_dir().as_str(), &path]);
+    // 写文件和索引在单独的任务中完成, 请求被取消时也不会留下没有索引的文件
+    let url = url.to_owned();
+    let index_path = path.clone();
+    tokio::spawn(async move {
+        tokio::fs::write(local, &data).await?;
+        network_image::insert(url.clone(), index_path, now).await?;
+        // 感知哈希在后台计算, 不阻塞图片加载
+        tokio::spawn(async move {
+            let _ = record_image_hash(&url, data, false).await;
+        });
+        Ok(())
+    })
+    .await??;
+    Ok(path)
+}
+
+/// 计算图片的感知哈希并写入 image_hash
+async fn record_image_hash(url: &str, data: bytes::Bytes, downloaded: bool) -> Result<u64> {
+    let hash = tokio::task::spawn_blocking(move || dhash(data.as_ref())).await??;
+    image_hash::save(url.to_owned(), hash as i64, downloaded).await?;
+    Ok(hash)
+}
+
+/// 在已记录的哈希中查找与 hash 相近的图片
+async fn similar_images(
+    hash: u64,
+    exclude_url: &str,
+    max_distance: u32,
+    downloaded_only: bool,
+) -> Result<Vec<SimilarImageDto>> {
+    let candidates = if downloaded_only {
+        image_hash::find_downloaded().await?
+    } else {
+        image_hash::find_all().await?
+    };
+    let mut result = vec![];
+    for candidate in candidates {
+        if candidate.url == exclude_url {
+            continue;
+        }
+        let distance = hamming_distance(hash, candidate.hash as u64);
+        if distance > max_distance {
+            continue;
+        }
+        let path = match network_image::find_by_url(candidate.url.clone()).await? {
+            Some(db_image) => join_paths(vec![get_network_image_dir().as_str(), &db_image.path]),
+            None => String::default(),
         };
-        Ok(join_paths(vec![get_network_image_dir().as_str(), &path]))
+        result.push(SimilarImageDto {
+            url: candidate.url,
+            path,
+            distance,
+            downloaded: candidate.downloaded,
+        });
+    }
+    result.sort_by_key(|r| r.distance);
+    Ok(result)
+}
+
+/// 查找与指定图片相似的已缓存或已下载图片
+///
+/// path_or_url 为本地文件路径或pixiv图片url, max_distance 为允许的最大汉明距离（0-64, 通常取 5-10）
+pub async fn find_similar_images(path_or_url: String, max_distance: u32) -> Result<Vec<SimilarImageDto>> {
+    let is_file = tokio::fs::metadata(&path_or_url)
+        .await
+        .is_ok_and(|m| m.is_file());
+    let hash = if is_file {
+        let data = tokio::fs::read(&path_or_url).await?;
+        tokio::task::spawn_blocking(move || dhash(&data)).await??
+    } else if let Some(db_hash) = image_hash::find_by_url(path_or_url.clone()).await? {
+        db_hash.hash as u64
+    } else {
+        let local = load_pixiv_image_(path_or_url.clone()).await?;
+        let data = bytes::Bytes::from(tokio::fs::read(local).await?);
+        record_image_hash(&path_or_url, data, false).await?
+    };
+    similar_images(hash, &path_or_url, max_distance, false).await
+}
+
+/// 下载pixiv的图片并生成缩略图
+///
+/// 缩略图等比缩放到 max_w * max_h 以内（原图更小时不放大），format 为 webp 或 jpeg。
+/// 每种尺寸/格式作为单独的 network_image 记录缓存，原图同样会被缓存。
+pub async fn load_pixiv_image_resized(
+    url: String,
+    max_w: u32,
+    max_h: u32,
+    format: String,
+) -> Result<String> {
+    if max_w == 0 || max_h == 0 {
+        return Err(anyhow::Error::msg("max_w and max_h must be greater than 0"));
+    }
+    let format = ResizedFormat::parse(&format)?;
+    let variant_key = format!("{}#{}x{}.{}", url, max_w, max_h, format.ext());
+    // 先确保原图已缓存（原图有自己的hash锁，不能在持有缩略图的锁时获取）
+    let original = load_pixiv_image_(url).await?;
+    let _lock = hash_lock(&variant_key).await;
+    if let Some(db_image) = network_image::find_by_url(variant_key.clone()).await? {
+        let local = join_paths(vec![get_network_image_dir().as_str(), &db_image.path]);
+        if tokio::fs::try_exists(&local).await.unwrap_or(false) {
+            return Ok(local);
+        }
+        let _ = network_image::delete_by_url(variant_key.clone()).await;
+    }
+    let now = chrono::Local::now().timestamp_millis();
+    let data = tokio::task::spawn_blocking(move || -> Result<Vec<u8>> {
+        let src = std::fs::read(original)?;
+        resize_image(&src, max_w, max_h, format)
     })
+    .await??;
+    let path = format!(
+        "{}_{}.{}",
+        hex::encode(md5::compute(variant_key.clone()).to_vec()),
+        &now,
+        format.ext(),
+    );
+    let local = join_paths(vec![get_network_image_dir().as_str(), &path]);
+    tokio::fs::write(&local, data).await?;
+    network_image::insert(variant_key, path, now).await?;
+    Ok(local)
+}
+
+fn placeholder_dto(model: image_placeholder::Model) -> ImagePlaceholderDto {
+    ImagePlaceholderDto {
+        url: model.url,
+        blurhash: model.blurhash,
+        palette: serde_json::from_str(&model.palette).unwrap_or_default(),
+        width: model.width,
+        height: model.height,
+    }
+}
+
+/// 计算图片的占位信息（blurhash + 主色调）并保存, 图片未缓存时会先下载
+pub async fn load_image_placeholder(url: String) -> Result<ImagePlaceholderDto> {
+    if let Some(model) = image_placeholder::find_by_url(url.clone()).await? {
+        return Ok(placeholder_dto(model));
+    }
+    let local = load_pixiv_image_(url.clone()).await?;
+    let _lock = hash_lock(&url).await;
+    // 等锁期间可能已被其他调用写入
+    if let Some(model) = image_placeholder::find_by_url(url.clone()).await? {
+        return Ok(placeholder_dto(model));
+    }
+    let ph = tokio::task::spawn_blocking(move || {
+        let data = std::fs::read(local)?;
+        placeholder(&data)
+    })
+    .await??;
+    let model = image_placeholder::insert(
+        url,
+        ph.blurhash,
+        serde_json::to_string(&ph.palette)?,
+        ph.width as i32,
+        ph.height as i32,
+    )
+    .await?;
+    Ok(placeholder_dto(model))
+}
+
+/// 读取已保存的占位信息, 不会发起网络请求（图片缓存被清除后仍可用）
+pub async fn cached_image_placeholder(url: String) -> Result<Option<ImagePlaceholderDto>> {
+    Ok(image_placeholder::find_by_url(url)
+        .await?
+        .map(placeholder_dto))
 }
 
 /// 清除图片缓存（Pixiv 图片缓存 + 索引表）
-pub fn clear_image_cache() -> Result<()> {
-    block_on(async {
-        // 先清理索引表（避免并发读取到旧记录）
-        let _ = network_image::delete_all().await?;
-        // 再删除实际缓存文件
-        let dir = get_network_image_dir().clone();
+pub async fn clear_image_cache() -> Result<()> {
+    // 先清理索引表（避免并发读取到旧记录）
+    let _ = network_image::delete_all().await?;
+    // 已下载图片的哈希用于查重, 需要保留
+    let _ = image_hash::delete_not_downloaded().await?;
+    // 再删除实际缓存文件, 文件很多时耗时较长, 在阻塞线程池中执行
+    let dir = get_network_image_dir().clone();
+    tokio::task::spawn_blocking(move || -> Result<()> {
         if Path::new(dir.as_str()).exists() {
             for entry in std::fs::read_dir(dir.as_str())? {
                 let entry = entry?;
@@ -293,61 +590,244 @@ pub fn clear_image_cache() -> Result<()> {
         }
         Ok(())
     })
+    .await?
 }
 
-pub fn user_detail(user_id: i64) -> Result<UserDetail> {
-    block_on(async { crate::local::client(2).await?.user_detail(user_id).await })
+pub async fn user_detail(user_id: i64) -> Result<UserDetail> {
+    let url = client(-1).await?.user_detail_url(user_id);
+    let (mut detail, stale): (UserDetail, bool) = cached_get(2, url).await?;
+    detail.stale = stale;
+    Ok(detail)
 }
 
-pub fn follow_user(user_id: i64, restrict: String) -> Result<()> {
-    block_on(async { crate::local::client(2).await?.follow_user(user_id, restrict).await })
+pub async fn follow_user(user_id: i64, restrict: Restrict) -> Result<()> {
+    with_auth_retry(2, |client| async move { client.follow_user(user_id, restrict).await })
+    .await?;
+    evict_follow_cache(user_id).await;
+    Ok(())
+}
+
+pub async fn unfollow_user(user_id: i64) -> Result<()> {
+    with_auth_retry(2, |client| async move {
+        client.unfollow_user(user_id).await
+    })
+    .await?;
+    evict_follow_cache(user_id).await;
+    Ok(())
 }
 
-pub fn unfollow_user(user_id: i64) -> Result<()> {
-    block_on(async { crate::local::client(2).await?.unfollow_user(user_id).await })
+pub async fn user_following(user_id: i64, restrict: Restrict) -> Result<UserPreviewsResponse> {
+    with_auth_retry(2, |client| async move { client.user_following(user_id, restrict).await })
+    .await
 }
 
-pub fn user_following(user_id: i64, restrict: String) -> Result<UserPreviewsResponse> {
-    block_on(async { 
-        crate::local::client(2).await?.user_following(user_id, restrict).await
+pub async fn user_bookmarks(user_id: i64, restrict: Restrict, tag: Option<String>) -> Result<IllustResponse> {
+    let url = client(-1).await?.user_bookmarks_url(user_id, restrict, tag);
+    let (mut result, stale): (IllustResponse, bool) = cached_get(2, url).await?;
+    result.stale = stale;
+    Ok(filter_illust_response(result).await)
+}
+
+pub async fn add_bookmark(illust_id: i64, restrict: Restrict) -> Result<()> {
+    with_auth_retry(2, |client| async move { client.add_bookmark(illust_id, restrict).await })
+    .await?;
+    evict_bookmark_cache().await;
+    Ok(())
+}
+
+pub async fn delete_bookmark(illust_id: i64) -> Result<()> {
+    with_auth_retry(2, |client| async move {
+        client.delete_bookmark(illust_id).await
     })
+    .await?;
+    evict_bookmark_cache().await;
+    Ok(())
 }
 
-pub fn user_bookmarks(user_id: i64, restrict: String, tag: Option<String>) -> Result<IllustResponse> {
-    block_on(async {
-        let result = crate::local::client(2).await?.user_bookmarks(user_id, restrict, tag).await?;
-        Ok(result.into())
+pub async fn current_user() -> Result<Option<UiCurrentUser>> {
+    let period = crate::local::TOKEN.lock().await;
+    if period.created_time == 0 {
+        return Ok(None);
+    }
+    let user = &period.token.user;
+    Ok(Some(UiCurrentUser {
+        user_id: user.id.parse().unwrap_or(0),
+        name: user.name.clone(),
+        account: user.account.clone(),
+        profile_image_url: user.profile_image_urls.px_170x170.clone(),
+        is_premium: user.is_premium,
+    }))
+}
+
+/// 监听登录状态变化（登录、token刷新、刷新失败、退出）, 新的监听会替换旧的
+pub fn auth_event_stream(sink: StreamSink<AuthEvent>) -> Result<()> {
+    set_auth_event_sink(sink);
+    Ok(())
+}
+
+/// 退出登录, 之后 current_user 返回 None
+///
+/// 同时删除该账号的响应缓存, clear_history 为 true 时还会清除该账号的本地浏览历史
+pub async fn logout(clear_history: bool) -> Result<()> {
+    let account = current_account_id().await;
+    logout_().await?;
+    if clear_history {
+        view_history::delete_account(&account).await?;
+    }
+    Ok(())
+}
+
+// ============= Accounts =============
+
+/// 所有已登录的账号, 登录新账号时会自动加入并切换
+pub async fn list_accounts() -> Result<Vec<UiAccount>> {
+    list_accounts_().await
+}
+
+/// 切换当前账号, 切换完成前开始的请求仍使用旧账号
+pub async fn switch_account(user_id: i64) -> Result<()> {
+    switch_account_(user_id).await
+}
+
+pub async fn remove_account(user_id: i64) -> Result<()> {
+    remove_account_(user_id).await
+}
+
+// ============= View History =============
+
+fn view_history_dto(model: view_history::Model) -> Result<ViewHistoryDto> {
+    Ok(ViewHistoryDto {
+        illust: serde_json::from_str(&model.illust_json)?,
+        view_count: model.view_count,
+        first_view_time: model.first_view_time,
+        last_view_time: model.last_view_time,
     })
 }
 
-pub fn add_bookmark(illust_id: i64, restrict: String) -> Result<()> {
-    block_on(async { crate::local::client(2).await?.add_bookmark(illust_id, restrict).await })
+/// 记录一次浏览（本地保存, 不依赖pixiv会员的浏览历史）
+pub async fn record_view(illust: Illust) -> Result<()> {
+    let mut tags = vec![];
+    for tag in &illust.tags {
+        tags.push(tag.name.clone());
+        if let Some(translated_name) = &tag.translated_name {
+            tags.push(translated_name.clone());
+        }
+    }
+    view_history::record(
+        &current_account_id().await,
+        illust.id,
+        serde_json::to_string(&illust)?,
+        illust.title.clone(),
+        illust.user.name.clone(),
+        tags.join(" "),
+    )
+    .await?;
+    Ok(())
 }
 
-pub fn delete_bookmark(illust_id: i64) -> Result<()> {
-    block_on(async { crate::local::client(2).await?.delete_bookmark(illust_id).await })
+/// 当前账号的浏览历史, 按最后浏览时间倒序分页, page 从 0 开始
+pub async fn list_history(page: i64, page_size: i64) -> Result<Vec<ViewHistoryDto>> {
+    if page < 0 || page_size <= 0 {
+        return Err(anyhow::Error::msg("invalid page or page_size"));
+    }
+    view_history::find_page(&current_account_id().await, page as u64, page_size as u64)
+        .await?
+        .into_iter()
+        .map(view_history_dto)
+        .collect()
+}
+
+/// 按作品id、标题、作者名或标签搜索当前账号的浏览历史
+pub async fn search_history(keyword: String) -> Result<Vec<ViewHistoryDto>> {
+    let keyword = keyword.trim().to_owned();
+    if keyword.is_empty() {
+        return Ok(vec![]);
+    }
+    view_history::search(&current_account_id().await, keyword)
+        .await?
+        .into_iter()
+        .map(view_history_dto)
+        .collect()
 }
 
-pub fn current_user() -> Result<Option<UiCurrentUser>> {
-    block_on(async {
-        let period = crate::local::TOKEN.lock().await;
-        if period.created_time == 0 {
-            return Ok(None);
+/// 清空 app-api 的响应缓存
+pub async fn clear_response_cache() -> Result<()> {
+    clear_response_cache_().await
+}
+
+/// 网络失败时是否使用过期的响应缓存, 只对有缓存时间的接口(排行榜、推荐、用户详情、热门标签)生效
+pub async fn get_response_cache_offline() -> bool {
+    get_response_cache_offline_().await
+}
+
+pub async fn set_response_cache_offline(value: bool) -> Result<()> {
+    set_response_cache_offline_(value).await
+}
+
+/// 清除当前账号的浏览历史
+pub async fn clear_history() -> Result<()> {
+    view_history::delete_account(&current_account_id().await).await?;
+    Ok(())
+}
+
+// ============= Ranking Snapshot =============
+
+/// 最新一期已公布的排行榜日期(yyyy-MM-dd)
+pub async fn latest_ranking_available_date() -> String {
+    latest_ranking_date()
+}
+
+/// 每小时自动记录的排行榜
+pub async fn get_ranking_capture_modes() -> Vec<RankMode> {
+    get_ranking_capture_modes_().await
+}
+
+pub async fn set_ranking_capture_modes(modes: Vec<RankMode>) -> Result<()> {
+    set_ranking_capture_modes_(modes).await?;
+    crate::RUNTIME.spawn(async {
+        if let Err(err) = capture_pending_rankings().await {
+            println!("Failed to capture rankings : {}", err);
         }
-        let user = &period.token.user;
-        Ok(Some(UiCurrentUser {
-            user_id: user.id.parse().unwrap_or(0),
-            name: user.name.clone(),
-            account: user.account.clone(),
-            profile_image_url: user.profile_image_urls.px_170x170.clone(),
-            is_premium: user.is_premium,
-        }))
-    })
+    });
+    Ok(())
+}
+
+/// 立即记录某一天的排行榜, date 为空时使用最新一期, 返回记录的作品数量
+pub async fn capture_ranking(mode: RankMode, date: String) -> Result<i64> {
+    let date = if date.is_empty() {
+        latest_ranking_date()
+    } else {
+        date
+    };
+    capture_ranking_(mode, date).await
+}
+
+/// 已记录的日期, 新的在前
+pub async fn ranking_snapshot_dates(mode: RankMode) -> Result<Vec<String>> {
+    ranking_snapshot_dates_(mode).await
+}
+
+pub async fn ranking_snapshot(mode: RankMode, date: String) -> Result<Vec<RankingEntryDto>> {
+    ranking_snapshot_(mode, date).await
+}
+
+/// 某个作者的作品在排行榜中的历史名次
+pub async fn ranking_history_of_user(mode: RankMode, user_id: i64) -> Result<Vec<RankingEntryDto>> {
+    ranking_history_of_user_(mode, user_id).await
+}
+
+/// 比较两天的排行榜: 新上榜、上升、下降、落榜
+pub async fn ranking_diff(mode: RankMode, from_date: String, to_date: String) -> Result<RankingDiff> {
+    ranking_diff_(mode, from_date, to_date).await
+}
+
+pub async fn clear_ranking_snapshots() -> Result<()> {
+    clear_ranking_snapshots_().await
 }
 
 // ============= Download Task Management =============
 
-pub fn create_download_task(
+pub async fn create_download_task(
     illust_id: i64,
     illust_title: String,
     page_index: i32,
@@ -356,149 +836,148 @@ pub fn create_download_task(
     target_path: String,
     save_target: String,
 ) -> Result<i64> {
-    block_on(async {
-        let task = download_task::insert(
-            illust_id,
-            illust_title,
-            page_index,
-            page_count,
-            url,
-            target_path,
-            save_target,
-        )
-        .await?;
-        Ok(task.id)
-    })
-}
-
-pub fn get_all_download_tasks() -> Result<Vec<DownloadTaskDto>> {
-    block_on(async {
-        let tasks = download_task::find_all().await?;
-        Ok(tasks.into_iter().map(|t| DownloadTaskDto {
-            id: t.id,
-            illust_id: t.illust_id,
-            illust_title: t.illust_title,
-            page_index: t.page_index,
-            page_count: t.page_count,
-            url: t.url,
-            target_path: t.target_path,
-            save_target: t.save_target,
-            status: t.status,
-            progress: t.progress,
-            error_message: t.error_message,
-            retry_count: t.retry_count,
-            created_time: t.created_time,
-            updated_time: t.updated_time,
-        }).collect())
-    })
-}
-
-pub fn get_pending_download_tasks() -> Result<Vec<DownloadTaskDto>> {
-    block_on(async {
-        let tasks = download_task::find_pending().await?;
-        Ok(tasks.into_iter().map(|t| DownloadTaskDto {
-            id: t.id,
-            illust_id: t.illust_id,
-            illust_title: t.illust_title,
-            page_index: t.page_index,
-            page_count: t.page_count,
-            url: t.url,
-            target_path: t.target_path,
-            save_target: t.save_target,
-            status: t.status,
-            progress: t.progress,
-            error_message: t.error_message,
-            retry_count: t.retry_count,
-            created_time: t.created_time,
-            updated_time: t.updated_time,
-        }).collect())
-    })
-}
-
-pub fn update_download_task_status(
+    let task = download_task::insert(
+        illust_id,
+        illust_title,
+        page_index,
+        page_count,
+        url,
+        target_path,
+        save_target,
+    )
+    .await?;
+    Ok(task.id)
+}
+
+pub async fn get_all_download_tasks() -> Result<Vec<DownloadTaskDto>> {
+    let tasks = download_task::find_all().await?;
+    Ok(tasks.into_iter().map(|t| DownloadTaskDto {
+        id: t.id,
+        illust_id: t.illust_id,
+        illust_title: t.illust_title,
+        page_index: t.page_index,
+        page_count: t.page_count,
+        url: t.url,
+        target_path: t.target_path,
+        save_target: t.save_target,
+        status: t.status,
+        progress: t.progress,
+        error_message: t.error_message,
+        retry_count: t.retry_count,
+        created_time: t.created_time,
+        updated_time: t.updated_time,
+    }).collect())
+}
+
+pub async fn get_pending_download_tasks() -> Result<Vec<DownloadTaskDto>> {
+    let tasks = download_task::find_pending().await?;
+    Ok(tasks.into_iter().map(|t| DownloadTaskDto {
+        id: t.id,
+        illust_id: t.illust_id,
+        illust_title: t.illust_title,
+        page_index: t.page_index,
+        page_count: t.page_count,
+        url: t.url,
+        target_path: t.target_path,
+        save_target: t.save_target,
+        status: t.status,
+        progress: t.progress,
+        error_message: t.error_message,
+        retry_count: t.retry_count,
+        created_time: t.created_time,
+        updated_time: t.updated_time,
+    }).collect())
+}
+
+pub async fn update_download_task_status(
     id: i64,
     status: String,
     progress: i32,
     error_message: String,
 ) -> Result<()> {
-    block_on(async {
-        download_task::update_status(id, status, progress, error_message).await?;
-        Ok(())
-    })
+    download_task::update_status(id, status, progress, error_message).await?;
+    Ok(())
 }
 
-pub fn retry_download_task(id: i64) -> Result<()> {
-    block_on(async {
-        download_task::retry_failed_task(id).await?;
-        Ok(())
-    })
+pub async fn retry_download_task(id: i64) -> Result<()> {
+    download_task::retry_failed_task(id).await?;
+    Ok(())
 }
 
-pub fn delete_download_task(id: i64) -> Result<()> {
-    block_on(async {
-        download_task::delete_by_id(id).await?;
-        Ok(())
-    })
+pub async fn delete_download_task(id: i64) -> Result<()> {
+    download_task::delete_by_id(id).await?;
+    Ok(())
 }
 
-pub fn delete_completed_download_tasks() -> Result<()> {
-    block_on(async {
-        download_task::delete_completed().await?;
-        Ok(())
-    })
+pub async fn delete_completed_download_tasks() -> Result<()> {
+    download_task::delete_completed().await?;
+    Ok(())
 }
 
-pub fn execute_download_task(id: i64) -> Result<()> {
-    block_on(async {
-        let task = download_task::find_by_id(id).await?;
-        if let Some(task) = task {
-            // Update status to downloading
-            download_task::update_status(id, "downloading".to_string(), 0, "".to_string()).await?;
-            
-            match _execute_single_download(&task).await {
-                std::result::Result::Ok(_) => {
-                    download_task::update_status(id, "completed".to_string(), 100, "".to_string()).await?;
-                }
-                Err(e) => {
-                    let error_msg = format!("{:?}", e);
-                    download_task::update_status(id, "failed".to_string(), 0, error_msg).await?;
-                    download_task::update_retry_count(id, task.retry_count + 1).await?;
-                }
+pub async fn execute_download_task(id: i64) -> Result<()> {
+    execute_download_task_(id, None).await
+}
+
+/// 同 execute_download_task, 可以通过 cancel(request_id) 取消, 取消的任务标记为失败但不计入重试次数
+pub async fn execute_download_task_cancellable(id: i64, request_id: String) -> Result<()> {
+    execute_download_task_(id, Some(request_id)).await
+}
+
+async fn execute_download_task_(id: i64, request_id: Option<String>) -> Result<()> {
+    let task = download_task::find_by_id(id).await?;
+    if let Some(task) = task {
+        // Update status to downloading
+        download_task::update_status(id, "downloading".to_string(), 0, "".to_string()).await?;
+
+        let result = match &request_id {
+            Some(request_id) => cancellable(request_id, _execute_single_download(&task)).await,
+            None => _execute_single_download(&task).await,
+        };
+        match result {
+            std::result::Result::Ok(_) => {
+                download_task::update_status(id, "completed".to_string(), 100, "".to_string()).await?;
+            }
+            Err(e) if e.downcast_ref::<Cancelled>().is_some() => {
+                download_task::update_status(id, "failed".to_string(), 0, e.to_string()).await?;
+            }
+            Err(e) => {
+                let error_msg = format!("{:?}", e);
+                download_task::update_status(id, "failed".to_string(), 0, error_msg).await?;
+                download_task::update_retry_count(id, task.retry_count + 1).await?;
             }
         }
-        Ok(())
-    })
+    }
+    Ok(())
 }
 
 async fn _execute_single_download(task: &download_task::Model) -> Result<()> {
     // Download the image to cache first
-    let _lock = hash_lock(&task.url).await;
-    let db_image = network_image::find_by_url(task.url.clone()).await?;
-    let _cached_path = match db_image {
-        Some(db_image) => {
-            join_paths(vec![get_network_image_dir().as_str(), &db_image.path])
-        }
-        None => {
-            let now = chrono::Local::now().timestamp_millis();
-            let client = crate::local::client(0).await?;
-            let data: bytes::Bytes = client.load_image_data(task.url.clone()).await?;
-            drop(client);
-            let f = image::guess_format(data.as_ref())?;
-            let ext = f.extensions_str()[0];
-            let path = format!(
-                "{}_{}.{}",
-                hex::encode(md5::compute(task.url.clone()).to_vec()),
-                &now,
-                ext,
-            );
-            let local = join_paths(vec![get_network_image_dir().as_str(), &path]);
-            std::fs::write(&local, data)?;
-            network_image::insert(task.url.clone(), path.clone(), now).await?;
-            local
-        }
-    };
+    let cached_path = load_pixiv_image_(task.url.clone()).await?;
+
+    // Remember the hash of downloaded images for duplicate detection
+    let data = bytes::Bytes::from(tokio::fs::read(cached_path).await?);
+    record_image_hash(&task.url, data, true).await?;
 
     // The actual saving to target will be handled by Flutter side
     // This just ensures the image is cached
     Ok(())
 }
+
+/// Find previously downloaded images that look like this task's image.
+///
+/// Call after `execute_download_task` and before saving to the target, so the
+/// UI can warn about near-duplicates (re-uploads, crops, re-encodes).
+pub async fn find_download_duplicates(id: i64, max_distance: u32) -> Result<Vec<SimilarImageDto>> {
+    let task = download_task::find_by_id(id)
+        .await?
+        .with_context(|| "download task not found")?;
+    let hash = match image_hash::find_by_url(task.url.clone()).await? {
+        Some(db_hash) => db_hash.hash as u64,
+        None => {
+            let local = load_pixiv_image_(task.url.clone()).await?;
+            let data = bytes::Bytes::from(tokio::fs::read(local).await?);
+            record_image_hash(&task.url, data, false).await?
+        }
+    };
+    similar_images(hash, &task.url, max_distance, true).await
+}
@@ -1,2 +1,19 @@
+use crate::AppAsyncRuntime;
+use flutter_rust_bridge::for_generated::{
+    NoOpErrorListener, SimpleExecutor, SimpleHandler, SimpleThreadPool,
+};
+
 pub mod simple;
 pub mod api;
+
+lazy_static::lazy_static! {
+    /// codegen 在 rust_input 中找到这个 handler 后会使用它代替默认的 handler,
+    /// async 的 api 在 RUNTIME 上执行, 不占用 flutter_rust_bridge 的线程池
+    pub static ref FLUTTER_RUST_BRIDGE_HANDLER: SimpleHandler<
+        SimpleExecutor<NoOpErrorListener, SimpleThreadPool, AppAsyncRuntime>,
+        NoOpErrorListener,
+    > = SimpleHandler::new(
+        SimpleExecutor::new(NoOpErrorListener, Default::default(), AppAsyncRuntime),
+        NoOpErrorListener,
+    );
+}
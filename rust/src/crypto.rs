@@ -0,0 +1,116 @@
+use crate::get_root;
+use crate::local::join_paths;
+use anyhow::{Context, Error, Result};
+use base64::Engine;
+use once_cell::sync::OnceCell;
+use openssl::symm::{decrypt_aead, encrypt_aead, Cipher};
+use std::io::Write;
+use std::path::Path;
+
+const KEY_FILE: &str = "secret.key";
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+const ENCRYPTED_PREFIX: &str = "enc:v1:";
+
+static KEY: OnceCell<Vec<u8>> = OnceCell::new();
+
+/// 保存加密密钥的位置, 以后可以接入各平台的系统密钥库(Keychain/Keystore/DPAPI)
+trait KeyStore {
+    fn load_or_create_key(&self) -> Result<Vec<u8>>;
+}
+
+/// 密钥保存在数据目录下的单独文件中
+///
+/// unix 上创建时权限为 0600, 仅当前用户可读写。
+/// Windows 上没有设置 ACL: 数据目录在程序所在目录下, 密钥文件继承该目录的权限,
+/// 可能对同一台电脑的其他用户可读。需要更强的保护时应换成基于 DPAPI 的 KeyStore。
+struct FileKeyStore {
+    path: String,
+}
+
+impl KeyStore for FileKeyStore {
+    fn load_or_create_key(&self) -> Result<Vec<u8>> {
+        if Path::new(&self.path).exists() {
+            let key = std::fs::read(&self.path)?;
+            if key.len() != KEY_LEN {
+                return Err(Error::msg("invalid key file"));
+            }
+            return Ok(key);
+        }
+        let mut key = vec![0u8; KEY_LEN];
+        openssl::rand::rand_bytes(&mut key)?;
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        // Windows 上使用默认权限, 见 FileKeyStore 的说明
+        let mut file = options.open(&self.path)?;
+        file.write_all(&key)?;
+        file.sync_all()?;
+        Ok(key)
+    }
+}
+
+fn key_store() -> impl KeyStore {
+    FileKeyStore {
+        path: join_paths(vec![get_root().as_str(), KEY_FILE]),
+    }
+}
+
+fn key() -> Result<&'static Vec<u8>> {
+    KEY.get_or_try_init(|| key_store().load_or_create_key())
+}
+
+/// 是否为 encrypt_string 输出的密文
+pub(crate) fn is_encrypted(value: &str) -> bool {
+    value.starts_with(ENCRYPTED_PREFIX)
+}
+
+/// AES-256-GCM 加密, 输出 前缀 + base64(nonce + 密文 + tag)
+pub(crate) fn encrypt_string(plain: &str) -> Result<String> {
+    let mut nonce = [0u8; NONCE_LEN];
+    openssl::rand::rand_bytes(&mut nonce)?;
+    let mut tag = [0u8; TAG_LEN];
+    let cipher_text = encrypt_aead(
+        Cipher::aes_256_gcm(),
+        key()?,
+        Some(&nonce),
+        ENCRYPTED_PREFIX.as_bytes(),
+        plain.as_bytes(),
+        &mut tag,
+    )?;
+    let mut buf = Vec::with_capacity(NONCE_LEN + cipher_text.len() + TAG_LEN);
+    buf.extend_from_slice(&nonce);
+    buf.extend_from_slice(&cipher_text);
+    buf.extend_from_slice(&tag);
+    Ok(format!(
+        "{}{}",
+        ENCRYPTED_PREFIX,
+        base64::prelude::BASE64_STANDARD.encode(buf)
+    ))
+}
+
+pub(crate) fn decrypt_string(value: &str) -> Result<String> {
+    let raw = value
+        .strip_prefix(ENCRYPTED_PREFIX)
+        .with_context(|| "not encrypted")?;
+    let buf = base64::prelude::BASE64_STANDARD.decode(raw)?;
+    if buf.len() < NONCE_LEN + TAG_LEN {
+        return Err(Error::msg("encrypted data too short"));
+    }
+    let (nonce, rest) = buf.split_at(NONCE_LEN);
+    let (cipher_text, tag) = rest.split_at(rest.len() - TAG_LEN);
+    let plain = decrypt_aead(
+        Cipher::aes_256_gcm(),
+        key()?,
+        Some(nonce),
+        ENCRYPTED_PREFIX.as_bytes(),
+        cipher_text,
+        tag,
+    )?;
+    Ok(String::from_utf8(plain)?)
+}
@@ -0,0 +1,80 @@
+use std::ops::Deref;
+use sea_orm::entity::prelude::*;
+use sea_orm::{EntityTrait, Set};
+use crate::entities::{create_index_a, create_table_if_not_exists, IMAGE_CACHE_DB, index_exists};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "image_hash")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub url: String,
+    pub hash: i64, // 64位 dHash, 按位存储为 i64
+    pub downloaded: bool, // 是否被下载任务保存过
+    pub created_time: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+pub(crate) async fn find_by_url(url: String) -> Result<Option<Model>, sea_orm::DbErr> {
+    Entity::find_by_id(url)
+        .one(IMAGE_CACHE_DB.get().unwrap().lock().await.deref())
+        .await
+}
+
+pub(crate) async fn find_all() -> Result<Vec<Model>, sea_orm::DbErr> {
+    Entity::find()
+        .all(IMAGE_CACHE_DB.get().unwrap().lock().await.deref())
+        .await
+}
+
+pub(crate) async fn find_downloaded() -> Result<Vec<Model>, sea_orm::DbErr> {
+    Entity::find()
+        .filter(Column::Downloaded.eq(true))
+        .all(IMAGE_CACHE_DB.get().unwrap().lock().await.deref())
+        .await
+}
+
+/// 写入hash, 已下载的标记不会被缓存覆盖
+pub(crate) async fn save(url: String, hash: i64, downloaded: bool) -> Result<(), sea_orm::DbErr> {
+    let now = chrono::Local::now().timestamp_millis();
+    let db = IMAGE_CACHE_DB.get().unwrap().lock().await;
+    let in_db = Entity::find_by_id(url.clone()).one(db.deref()).await?;
+    match in_db {
+        Some(in_db) => {
+            let was_downloaded = in_db.downloaded;
+            let mut data: ActiveModel = in_db.into();
+            data.hash = Set(hash);
+            data.downloaded = Set(was_downloaded || downloaded);
+            data.update(db.deref()).await?;
+        }
+        None => {
+            ActiveModel {
+                url: Set(url),
+                hash: Set(hash),
+                downloaded: Set(downloaded),
+                created_time: Set(now),
+            }
+            .insert(db.deref())
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+pub(crate) async fn delete_not_downloaded() -> Result<u64, sea_orm::DbErr> {
+    let res = Entity::delete_many()
+        .filter(Column::Downloaded.eq(false))
+        .exec(IMAGE_CACHE_DB.get().unwrap().lock().await.deref())
+        .await?;
+    Ok(res.rows_affected)
+}
+
+pub(crate) async fn init(db: &DatabaseConnection) {
+    create_table_if_not_exists(db, Entity).await;
+    if !index_exists(db, "image_hash", "image_hash_idx_downloaded").await {
+        create_index_a(db, "image_hash", vec!["downloaded"], "image_hash_idx_downloaded", false).await;
+    }
+}
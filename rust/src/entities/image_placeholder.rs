@@ -0,0 +1,52 @@
+use std::ops::Deref;
+use sea_orm::entity::prelude::*;
+use sea_orm::{EntityTrait, Set};
+use crate::entities::{create_table_if_not_exists, IMAGE_CACHE_DB};
+
+/// 图片占位信息, 与 network_image 使用相同的url, 清除图片缓存时保留
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "image_placeholder")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub url: String,
+    pub blurhash: String,
+    pub palette: String, // json数组, 0xAARRGGBB, 按占比从高到低
+    pub width: i32,
+    pub height: i32,
+    pub created_time: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+pub(crate) async fn find_by_url(url: String) -> Result<Option<Model>, sea_orm::DbErr> {
+    Entity::find_by_id(url)
+        .one(IMAGE_CACHE_DB.get().unwrap().lock().await.deref())
+        .await
+}
+
+pub(crate) async fn insert(
+    url: String,
+    blurhash: String,
+    palette: String,
+    width: i32,
+    height: i32,
+) -> Result<Model, sea_orm::DbErr> {
+    let now = chrono::Local::now().timestamp_millis();
+    ActiveModel {
+        url: Set(url),
+        blurhash: Set(blurhash),
+        palette: Set(palette),
+        width: Set(width),
+        height: Set(height),
+        created_time: Set(now),
+    }
+    .insert(IMAGE_CACHE_DB.get().unwrap().lock().await.deref())
+    .await
+}
+
+pub(crate) async fn init(db: &DatabaseConnection) {
+    create_table_if_not_exists(db, Entity).await;
+}
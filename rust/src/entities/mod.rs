@@ -6,13 +6,21 @@ use sea_orm::{ConnectionTrait, EntityTrait, Schema, Statement};
 use std::time::Duration;
 use tokio::sync::Mutex;
 
+pub(crate) mod image_hash;
+pub(crate) mod image_placeholder;
 pub(crate) mod network_image;
 pub(crate) mod property;
+pub(crate) mod ranking_snapshot;
+pub(crate) mod response_cache;
 pub(crate) mod download_task;
+pub(crate) mod view_history;
 
 static IMAGE_CACHE_DB: OnceCell<Mutex<DatabaseConnection>> = OnceCell::new();
 static PROPERTIES_DB: OnceCell<Mutex<DatabaseConnection>> = OnceCell::new();
 static DOWNLOAD_TASK_DB: OnceCell<Mutex<DatabaseConnection>> = OnceCell::new();
+static HISTORY_DB: OnceCell<Mutex<DatabaseConnection>> = OnceCell::new();
+static RESPONSE_CACHE_DB: OnceCell<Mutex<DatabaseConnection>> = OnceCell::new();
+static RANKING_DB: OnceCell<Mutex<DatabaseConnection>> = OnceCell::new();
 
 pub(crate) async fn init_databases(){
     {
@@ -33,6 +41,24 @@ pub(crate) async fn init_databases(){
         setup_download_task_db(&db).await;
         DOWNLOAD_TASK_DB.set(Mutex::new(db)).unwrap();
     }
+    {
+        let path = join_paths(vec![get_root().as_str(),"history.db"]);
+        let db = connect_db(&path).await;
+        setup_history_db(&db).await;
+        HISTORY_DB.set(Mutex::new(db)).unwrap();
+    }
+    {
+        let path = join_paths(vec![get_root().as_str(),"response_cache.db"]);
+        let db = connect_db(&path).await;
+        setup_response_cache_db(&db).await;
+        RESPONSE_CACHE_DB.set(Mutex::new(db)).unwrap();
+    }
+    {
+        let path = join_paths(vec![get_root().as_str(),"ranking.db"]);
+        let db = connect_db(&path).await;
+        setup_ranking_db(&db).await;
+        RANKING_DB.set(Mutex::new(db)).unwrap();
+    }
 }
 
 pub(crate) async fn connect_db(path: &str) -> DatabaseConnection {
@@ -101,6 +127,27 @@ pub(crate) async fn index_exists(
         > 0
 }
 
+pub(crate) async fn column_exists(
+    db: &DatabaseConnection,
+    table_name: &str,
+    column_name: &str,
+) -> bool {
+    let stmt = Statement::from_string(
+        db.get_database_backend(),
+        format!(
+            "SELECT COUNT(*) AS c FROM pragma_table_info('{}') WHERE name='{}';",
+            table_name, column_name,
+        ),
+    );
+    db.query_one(stmt)
+        .await
+        .unwrap()
+        .unwrap()
+        .try_get::<i32>("", "c")
+        .unwrap()
+        > 0
+}
+
 pub(crate) async fn create_index_a(
     db: &DatabaseConnection,
     table_name: &str,
@@ -132,7 +179,9 @@ pub(crate) async fn create_index(
 }
 
 async fn setup_image_cache_db(db: &DatabaseConnection) {
-    network_image::init(db).await
+    network_image::init(db).await;
+    image_hash::init(db).await;
+    image_placeholder::init(db).await;
 }
 
 async fn setup_properties_db(db: &DatabaseConnection) {
@@ -142,3 +191,15 @@ async fn setup_properties_db(db: &DatabaseConnection) {
 async fn setup_download_task_db(db: &DatabaseConnection) {
     download_task::init(db).await
 }
+
+async fn setup_history_db(db: &DatabaseConnection) {
+    view_history::init(db).await
+}
+
+async fn setup_response_cache_db(db: &DatabaseConnection) {
+    response_cache::init(db).await
+}
+
+async fn setup_ranking_db(db: &DatabaseConnection) {
+    ranking_snapshot::init(db).await
+}
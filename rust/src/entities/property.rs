@@ -1,7 +1,7 @@
 use crate::entities::PROPERTIES_DB;
 use crate::entities::{create_index_a, create_table_if_not_exists, index_exists};
 use sea_orm::entity::prelude::*;
-use sea_orm::{EntityTrait, Set};
+use sea_orm::{ConnectionTrait, EntityTrait, Set, Statement};
 use std::ops::Deref;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Default)]
@@ -59,6 +59,7 @@ pub(crate) async fn load_i64_property(k: String) -> Result<i64, anyhow::Error> {
     Ok(if value.eq("") { 0 } else { value.parse()? })
 }
 
+#[allow(dead_code)]
 pub(crate) async fn save_i64_property(k: String, v: i64) -> Result<(), DbErr> {
     save_property(k, format!("{}", v)).await
 }
@@ -71,3 +72,11 @@ pub(crate) async fn load_bool_property(k: String) -> Result<bool, anyhow::Error>
 pub(crate) async fn save_bool_property(k: String, v: bool) -> Result<(), DbErr> {
     save_property(k, format!("{}", v)).await
 }
+
+/// 回收空闲页, 避免被覆盖的旧值残留在数据库文件中
+pub(crate) async fn vacuum() -> Result<(), DbErr> {
+    let db = PROPERTIES_DB.get().unwrap().lock().await;
+    let stmt = Statement::from_string(db.get_database_backend(), "VACUUM;".to_owned());
+    db.execute(stmt).await?;
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use crate::entities::{create_index_a, create_table_if_not_exists, index_exists, RANKING_DB};
+use sea_orm::entity::prelude::*;
+use sea_orm::{EntityTrait, QueryOrder, QuerySelect, Set, TransactionTrait};
+use std::ops::Deref;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "ranking_snapshot")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub mode: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub date: String, // yyyy-MM-dd
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub rank: i64, // 从1开始
+    pub illust_id: i64,
+    pub user_id: i64,
+    pub illust_json: String, // 记录时的 Illust 快照
+    pub captured_time: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// 替换某个排行榜某一天的全部记录
+pub(crate) async fn replace(
+    mode: &str,
+    date: &str,
+    entries: Vec<(i64, i64, String)>,
+) -> Result<(), DbErr> {
+    let now = chrono::Local::now().timestamp_millis();
+    let db = RANKING_DB.get().unwrap().lock().await;
+    let txn = db.begin().await?;
+    Entity::delete_many()
+        .filter(Column::Mode.eq(mode))
+        .filter(Column::Date.eq(date))
+        .exec(&txn)
+        .await?;
+    for (idx, (illust_id, user_id, illust_json)) in entries.into_iter().enumerate() {
+        ActiveModel {
+            mode: Set(mode.to_owned()),
+            date: Set(date.to_owned()),
+            rank: Set(idx as i64 + 1),
+            illust_id: Set(illust_id),
+            user_id: Set(user_id),
+            illust_json: Set(illust_json),
+            captured_time: Set(now),
+        }
+        .insert(&txn)
+        .await?;
+    }
+    txn.commit().await
+}
+
+pub(crate) async fn find(mode: &str, date: &str) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::Mode.eq(mode))
+        .filter(Column::Date.eq(date))
+        .order_by_asc(Column::Rank)
+        .all(RANKING_DB.get().unwrap().lock().await.deref())
+        .await
+}
+
+pub(crate) async fn exists(mode: &str, date: &str) -> Result<bool, DbErr> {
+    Ok(Entity::find()
+        .filter(Column::Mode.eq(mode))
+        .filter(Column::Date.eq(date))
+        .one(RANKING_DB.get().unwrap().lock().await.deref())
+        .await?
+        .is_some())
+}
+
+/// 已记录的日期, 新的在前
+pub(crate) async fn dates(mode: &str) -> Result<Vec<String>, DbErr> {
+    Entity::find()
+        .select_only()
+        .column(Column::Date)
+        .distinct()
+        .filter(Column::Mode.eq(mode))
+        .order_by_desc(Column::Date)
+        .into_tuple()
+        .all(RANKING_DB.get().unwrap().lock().await.deref())
+        .await
+}
+
+/// 某个作者在排行榜中的全部记录
+pub(crate) async fn find_by_user(mode: &str, user_id: i64) -> Result<Vec<Model>, DbErr> {
+    Entity::find()
+        .filter(Column::Mode.eq(mode))
+        .filter(Column::UserId.eq(user_id))
+        .order_by_desc(Column::Date)
+        .order_by_asc(Column::Rank)
+        .all(RANKING_DB.get().unwrap().lock().await.deref())
+        .await
+}
+
+pub(crate) async fn delete_all() -> Result<u64, DbErr> {
+    let res = Entity::delete_many()
+        .exec(RANKING_DB.get().unwrap().lock().await.deref())
+        .await?;
+    Ok(res.rows_affected)
+}
+
+pub(crate) async fn init(db: &DatabaseConnection) {
+    create_table_if_not_exists(db, Entity).await;
+    if !index_exists(db, "ranking_snapshot", "ranking_snapshot_idx_user_id").await {
+        create_index_a(
+            db,
+            "ranking_snapshot",
+            vec!["mode", "user_id"],
+            "ranking_snapshot_idx_user_id",
+            false,
+        )
+        .await;
+    }
+}
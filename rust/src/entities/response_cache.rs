@@ -0,0 +1,117 @@
+use crate::entities::{create_index_a, create_table_if_not_exists, index_exists, RESPONSE_CACHE_DB};
+use sea_orm::entity::prelude::*;
+use sea_orm::{Condition, EntityTrait, Set};
+use std::ops::Deref;
+
+/// 缓存保留7天
+const KEEP_MILLIS: i64 = 7 * 24 * 60 * 60 * 1000;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "response_cache")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub cache_key: String, // 账号id|url
+    pub account: String,
+    pub url: String,
+    pub body: String,
+    pub created_time: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+pub(crate) fn cache_key(account: &str, url: &str) -> String {
+    format!("{}|{}", account, url)
+}
+
+pub(crate) async fn find(account: &str, url: &str) -> Result<Option<Model>, DbErr> {
+    Entity::find_by_id(cache_key(account, url))
+        .one(RESPONSE_CACHE_DB.get().unwrap().lock().await.deref())
+        .await
+}
+
+pub(crate) async fn save(account: &str, url: &str, body: String) -> Result<(), DbErr> {
+    let now = chrono::Local::now().timestamp_millis();
+    let key = cache_key(account, url);
+    let db = RESPONSE_CACHE_DB.get().unwrap().lock().await;
+    let in_db = Entity::find_by_id(key.clone()).one(db.deref()).await?;
+    match in_db {
+        Some(in_db) => {
+            let mut data: ActiveModel = in_db.into();
+            data.body = Set(body);
+            data.created_time = Set(now);
+            data.update(db.deref()).await?;
+        }
+        None => {
+            ActiveModel {
+                cache_key: Set(key),
+                account: Set(account.to_owned()),
+                url: Set(url.to_owned()),
+                body: Set(body),
+                created_time: Set(now),
+            }
+            .insert(db.deref())
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+/// 删除账号下 url 等于 urls 之一或包含 fragments 之一的缓存
+pub(crate) async fn delete_urls(
+    account: &str,
+    urls: Vec<String>,
+    fragments: &[&str],
+) -> Result<u64, DbErr> {
+    let mut matches = Condition::any();
+    for url in urls {
+        matches = matches.add(Column::Url.eq(url));
+    }
+    for fragment in fragments {
+        matches = matches.add(Column::Url.contains(*fragment));
+    }
+    let res = Entity::delete_many()
+        .filter(Column::Account.eq(account))
+        .filter(matches)
+        .exec(RESPONSE_CACHE_DB.get().unwrap().lock().await.deref())
+        .await?;
+    Ok(res.rows_affected)
+}
+
+/// 删除账号的全部缓存(退出登录或删除账号时)
+pub(crate) async fn delete_account(account: &str) -> Result<u64, DbErr> {
+    let res = Entity::delete_many()
+        .filter(Column::Account.eq(account))
+        .exec(RESPONSE_CACHE_DB.get().unwrap().lock().await.deref())
+        .await?;
+    Ok(res.rows_affected)
+}
+
+pub(crate) async fn delete_all() -> Result<u64, DbErr> {
+    let res = Entity::delete_many()
+        .exec(RESPONSE_CACHE_DB.get().unwrap().lock().await.deref())
+        .await?;
+    Ok(res.rows_affected)
+}
+
+pub(crate) async fn init(db: &DatabaseConnection) {
+    create_table_if_not_exists(db, Entity).await;
+    if !index_exists(db, "response_cache", "response_cache_idx_created_time").await {
+        create_index_a(
+            db,
+            "response_cache",
+            vec!["created_time"],
+            "response_cache_idx_created_time",
+            false,
+        )
+        .await;
+    }
+    // 离线时也不再使用太久以前的缓存
+    let expire = chrono::Local::now().timestamp_millis() - KEEP_MILLIS;
+    let _ = Entity::delete_many()
+        .filter(Column::CreatedTime.lt(expire))
+        .exec(db)
+        .await;
+}
@@ -0,0 +1,155 @@
+use std::ops::Deref;
+use sea_orm::entity::prelude::*;
+use sea_orm::{Condition, EntityTrait, PaginatorTrait, QueryOrder, Set, Statement};
+use crate::entities::{
+    column_exists, create_index_a, create_table, create_table_if_not_exists, has_table, index_exists,
+    HISTORY_DB,
+};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "view_history")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub account: String, // 账号id, 按账号区分之前的记录为空
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub illust_id: i64,
+    pub illust_json: String, // 最后一次浏览时的 Illust 快照
+    pub title: String,
+    pub user_name: String,
+    pub tags: String, // 标签及翻译, 空格分隔, 用于搜索
+    pub view_count: i32,
+    pub first_view_time: i64,
+    pub last_view_time: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// 当前账号的记录和按账号区分之前的记录
+fn account_condition(account: &str) -> Condition {
+    Condition::any()
+        .add(Column::Account.eq(account))
+        .add(Column::Account.eq(""))
+}
+
+pub(crate) async fn record(
+    account: &str,
+    illust_id: i64,
+    illust_json: String,
+    title: String,
+    user_name: String,
+    tags: String,
+) -> Result<(), sea_orm::DbErr> {
+    let now = chrono::Local::now().timestamp_millis();
+    let db = HISTORY_DB.get().unwrap().lock().await;
+    let mut in_db = Entity::find_by_id((account.to_owned(), illust_id))
+        .one(db.deref())
+        .await?;
+    if in_db.is_none() && !account.is_empty() {
+        // 按账号区分之前的记录归入之后浏览它的账号
+        Entity::update_many()
+            .col_expr(Column::Account, Expr::value(account))
+            .filter(Column::Account.eq(""))
+            .filter(Column::IllustId.eq(illust_id))
+            .exec(db.deref())
+            .await?;
+        in_db = Entity::find_by_id((account.to_owned(), illust_id))
+            .one(db.deref())
+            .await?;
+    }
+    match in_db {
+        Some(in_db) => {
+            let view_count = in_db.view_count;
+            let mut data: ActiveModel = in_db.into();
+            data.illust_json = Set(illust_json);
+            data.title = Set(title);
+            data.user_name = Set(user_name);
+            data.tags = Set(tags);
+            data.view_count = Set(view_count + 1);
+            data.last_view_time = Set(now);
+            data.update(db.deref()).await?;
+        }
+        None => {
+            ActiveModel {
+                account: Set(account.to_owned()),
+                illust_id: Set(illust_id),
+                illust_json: Set(illust_json),
+                title: Set(title),
+                user_name: Set(user_name),
+                tags: Set(tags),
+                view_count: Set(1),
+                first_view_time: Set(now),
+                last_view_time: Set(now),
+            }
+            .insert(db.deref())
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+pub(crate) async fn find_page(
+    account: &str,
+    page: u64,
+    page_size: u64,
+) -> Result<Vec<Model>, sea_orm::DbErr> {
+    Entity::find()
+        .filter(account_condition(account))
+        .order_by_desc(Column::LastViewTime)
+        .paginate(HISTORY_DB.get().unwrap().lock().await.deref(), page_size)
+        .fetch_page(page)
+        .await
+}
+
+pub(crate) async fn search(account: &str, keyword: String) -> Result<Vec<Model>, sea_orm::DbErr> {
+    let mut condition = Condition::any()
+        .add(Column::Title.contains(keyword.as_str()))
+        .add(Column::UserName.contains(keyword.as_str()))
+        .add(Column::Tags.contains(keyword.as_str()));
+    if let std::result::Result::Ok(id) = keyword.trim().parse::<i64>() {
+        condition = condition.add(Column::IllustId.eq(id));
+    }
+    Entity::find()
+        .filter(account_condition(account))
+        .filter(condition)
+        .order_by_desc(Column::LastViewTime)
+        .all(HISTORY_DB.get().unwrap().lock().await.deref())
+        .await
+}
+
+/// 删除账号的浏览记录(包括按账号区分之前的记录)
+pub(crate) async fn delete_account(account: &str) -> Result<u64, sea_orm::DbErr> {
+    let res = Entity::delete_many()
+        .filter(account_condition(account))
+        .exec(HISTORY_DB.get().unwrap().lock().await.deref())
+        .await?;
+    Ok(res.rows_affected)
+}
+
+pub(crate) async fn init(db: &DatabaseConnection) {
+    if has_table(db, "view_history").await && !column_exists(db, "view_history", "account").await {
+        migrate_to_accounts(db).await;
+    }
+    create_table_if_not_exists(db, Entity).await;
+    if !index_exists(db, "view_history", "view_history_idx_last_view_time").await {
+        create_index_a(db, "view_history", vec!["last_view_time"], "view_history_idx_last_view_time", false).await;
+    }
+}
+
+/// 旧版本的表以 illust_id 为主键, 重建为 (account, illust_id) 并保留已有记录
+async fn migrate_to_accounts(db: &DatabaseConnection) {
+    let backend = db.get_database_backend();
+    let exec = |sql: &str| db.execute(Statement::from_string(backend, sql.to_owned()));
+    exec("DROP INDEX IF EXISTS view_history_idx_last_view_time;").await.unwrap();
+    exec("ALTER TABLE view_history RENAME TO view_history_old;").await.unwrap();
+    create_table(db, Entity).await;
+    exec(
+        "INSERT INTO view_history (account, illust_id, illust_json, title, user_name, tags, view_count, first_view_time, last_view_time) \
+         SELECT '', illust_id, illust_json, title, user_name, tags, view_count, first_view_time, last_view_time FROM view_history_old;",
+    )
+    .await
+    .unwrap();
+    exec("DROP TABLE view_history_old;").await.unwrap();
+}
@@ -0,0 +1,404 @@
+//! 在本地启动 fake_server, 将服务地址指向它, 经过 api 和真实的 ReqwestBackend 请求
+
+use crate::api::api::*;
+use crate::entities::view_history;
+use crate::local::apply_network_settings;
+use crate::pixirust::entities::{Illust, Restrict};
+use crate::udto::UiLoginByCodeQuery;
+use crate::{block_on, test_env, AppAsyncRuntime};
+use fake_server::Faults;
+use flutter_rust_bridge::for_generated::BaseAsyncRuntime;
+use std::collections::HashSet;
+
+/// 启动服务并让客户端使用它, 返回服务地址
+pub(crate) async fn start_server(faults: Faults) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(fake_server::serve(listener, faults, vec![]));
+    set_server_urls(base.clone(), base.clone(), base.clone())
+        .await
+        .unwrap();
+    // 其他测试可能替换过传输层
+    apply_network_settings().await.unwrap();
+    base
+}
+
+pub(crate) async fn login(code: &str) -> anyhow::Result<bool> {
+    login_by_code(UiLoginByCodeQuery {
+        code: code.to_owned(),
+        verify: "verify".to_owned(),
+    })
+    .await
+}
+
+pub(crate) async fn server_requests(base: &str) -> Vec<String> {
+    let text = reqwest::get(format!("{base}/_requests"))
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    serde_json::from_str(&text).unwrap()
+}
+
+#[test]
+fn login_browse_and_bookmark() {
+    let _env = test_env();
+    block_on(async {
+        let base = start_server(Faults::default()).await;
+
+        assert!(login("invalid").await.is_err());
+        assert!(login("code").await.unwrap());
+        let user = current_user().await.unwrap().unwrap();
+        assert_eq!(user.name, "fake");
+
+        // fake_server 的推荐共 3 页, 每页 30 个
+        let handle = cursor_open(illust_recommended_first_url().await.unwrap());
+        let mut ids = HashSet::new();
+        let mut pages = 0;
+        while let Some(page) = cursor_next(handle).await.unwrap() {
+            pages += 1;
+            ids.extend(page.illusts.iter().map(|i| i.id));
+        }
+        cursor_close(handle);
+        assert_eq!(pages, 3);
+        assert_eq!(ids.len(), 90);
+
+        follow_user(5, Restrict::Public).await.unwrap();
+        add_bookmark(7, Restrict::Private).await.unwrap();
+        let requests = server_requests(&base).await;
+        assert!(requests.contains(&"POST /v1/user/follow/add".to_owned()));
+        assert!(requests.contains(&"POST /v2/illust/bookmark/add".to_owned()));
+
+        // 图片地址替换为 fake_server
+        let path = load_pixiv_image(
+            "https://i.pximg.net/c/540x540_70/img-master/img/2024/01/01/00/00/00/1_p0_master1200.jpg"
+                .to_owned(),
+        )
+        .await
+        .unwrap();
+        let data = std::fs::read(path).unwrap();
+        assert!(data.starts_with(b"\x89PNG"));
+        assert!(server_requests(&base)
+            .await
+            .iter()
+            .any(|r| r.starts_with("GET /c/540x540_70/")));
+    });
+}
+
+#[test]
+fn retries_rate_limited_requests() {
+    let _env = test_env();
+    block_on(async {
+        let base = start_server(Faults {
+            fail_every: 2,
+            fail_status: 429,
+            ..Default::default()
+        })
+        .await;
+        assert!(login("code").await.unwrap());
+        // 第2个请求返回 429 (Retry-After: 1), 等待后重试成功
+        illust_trending_tags().await.unwrap();
+        let requests = server_requests(&base).await;
+        assert_eq!(
+            requests,
+            vec![
+                "POST /auth/token",
+                "GET /v1/trending-tags/illust?filter=for_android",
+                "GET /v1/trending-tags/illust?filter=for_android",
+            ]
+        );
+    });
+}
+
+#[test]
+fn retries_server_errors() {
+    let _env = test_env();
+    block_on(async {
+        let base = start_server(Faults {
+            fail_every: 2,
+            fail_status: 503,
+            ..Default::default()
+        })
+        .await;
+        assert!(login("code").await.unwrap());
+        // 第2个请求返回 503, GET 稍后重试成功
+        illust_trending_tags().await.unwrap();
+        // 第4个请求返回 503, POST 不重发
+        assert!(add_bookmark(7, Restrict::Public).await.is_err());
+        let requests = server_requests(&base).await;
+        assert_eq!(
+            requests,
+            vec![
+                "POST /auth/token",
+                "GET /v1/trending-tags/illust?filter=for_android",
+                "GET /v1/trending-tags/illust?filter=for_android",
+                "POST /v2/illust/bookmark/add",
+            ]
+        );
+    });
+}
+
+#[test]
+fn truncated_bodies_are_not_stored() {
+    let _env = test_env();
+    block_on(async {
+        let base = start_server(Faults {
+            truncate_every: 2,
+            ..Default::default()
+        })
+        .await;
+        assert!(login("code").await.unwrap());
+
+        // 第2个请求的响应体被截断, 不写入响应缓存
+        let url = format!("{base}/v1/user/detail?filter=for_android&user_id=5");
+        assert!(user_detail(5).await.is_err());
+        assert!(crate::entities::response_cache::find("1", &url)
+            .await
+            .unwrap()
+            .is_none());
+        user_detail(5).await.unwrap();
+        assert!(crate::entities::response_cache::find("1", &url)
+            .await
+            .unwrap()
+            .is_some());
+
+        // 第4个请求下载图片被截断, 不留下文件和索引
+        let image =
+            "https://i.pximg.net/img-master/img/2024/01/01/00/00/00/9001_p0_master1200.jpg".to_owned();
+        let prefix = hex::encode(md5::compute(&image).to_vec());
+        let cached_files = || {
+            std::fs::read_dir(crate::get_network_image_dir())
+                .unwrap()
+                .filter(|e| {
+                    e.as_ref()
+                        .unwrap()
+                        .file_name()
+                        .to_string_lossy()
+                        .starts_with(&prefix)
+                })
+                .count()
+        };
+        assert!(load_pixiv_image(image.clone()).await.is_err());
+        assert_eq!(cached_files(), 0);
+        assert!(crate::entities::network_image::find_by_url(image.clone())
+            .await
+            .unwrap()
+            .is_none());
+        let path = load_pixiv_image(image.clone()).await.unwrap();
+        assert!(std::fs::read(path).unwrap().starts_with(b"\x89PNG"));
+        assert_eq!(cached_files(), 1);
+    });
+}
+
+#[test]
+fn proxy_test_does_not_block_settings() {
+    let _env = test_env();
+    block_on(async {
+        let base = start_server(Faults {
+            latency_ms: 300,
+            ..Default::default()
+        })
+        .await;
+        let probes = tokio::spawn(test_proxy());
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        // 探测进行中也可以修改设置(需要客户端的写锁)
+        let start = std::time::Instant::now();
+        set_server_urls(base.clone(), base.clone(), base.clone())
+            .await
+            .unwrap();
+        assert!(start.elapsed() < std::time::Duration::from_millis(200));
+        let results = probes.await.unwrap();
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|r| r.status == 404), "{results:?}");
+    });
+}
+
+#[test]
+fn rate_limit_wait_does_not_block_settings() {
+    let _env = test_env();
+    block_on(async {
+        let base = start_server(Faults {
+            fail_every: 2,
+            fail_status: 429,
+            ..Default::default()
+        })
+        .await;
+        assert!(login("code").await.unwrap());
+        // 第2个请求返回 429, 等待 1 秒后重试
+        let request = tokio::spawn(illust_trending_tags());
+        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+        let start = std::time::Instant::now();
+        set_server_urls(base.clone(), base.clone(), base.clone())
+            .await
+            .unwrap();
+        assert!(start.elapsed() < std::time::Duration::from_millis(200));
+        assert!(!request.is_finished());
+        request.await.unwrap().unwrap();
+    });
+}
+
+#[test]
+fn cursor_collect_all_releases_cursor_between_pages() {
+    let _env = test_env();
+    block_on(async {
+        start_server(Faults {
+            latency_ms: 300,
+            ..Default::default()
+        })
+        .await;
+        assert!(login("code").await.unwrap());
+        let first_url = illust_recommended_first_url().await.unwrap();
+
+        // collect_all 读取第1页时调用 cursor_next, 拿到第2页, collect_all 继续读取第3页
+        clear_response_cache().await.unwrap();
+        let handle = cursor_open(first_url.clone());
+        let collect = tokio::spawn(cursor_collect_all(handle, 1000));
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        let page = cursor_next(handle).await.unwrap().unwrap();
+        assert!(!collect.is_finished());
+        let collected = collect.await.unwrap().unwrap();
+        cursor_close(handle);
+        assert_eq!(page.illusts.len(), 30);
+        assert_eq!(collected.len(), 60);
+        let ids: HashSet<i64> = collected
+            .iter()
+            .chain(page.illusts.iter())
+            .map(|i| i.id)
+            .collect();
+        assert_eq!(ids.len(), 90);
+
+        // 读取第1页时关闭游标, collect_all 返回已读取的作品
+        clear_response_cache().await.unwrap();
+        let handle = cursor_open(first_url);
+        let collect = tokio::spawn(cursor_collect_all(handle, 1000));
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        cursor_close(handle);
+        assert_eq!(collect.await.unwrap().unwrap().len(), 30);
+        assert!(cursor_next(handle).await.is_err());
+    });
+}
+
+#[test]
+fn mutations_evict_cached_responses() {
+    let _env = test_env();
+    block_on(async {
+        let base = start_server(Faults::default()).await;
+        assert!(login("code").await.unwrap());
+        let count = |requests: &[String], prefix: &str| {
+            requests.iter().filter(|r| r.starts_with(prefix)).count()
+        };
+
+        user_detail(5).await.unwrap();
+        user_detail(5).await.unwrap();
+        let ranking = illust_rank_first_url(crate::udto::UiIllustRankQuery {
+            mode: crate::pixirust::entities::RankMode::Day,
+            date: String::new(),
+        })
+        .await
+        .unwrap();
+        illust_from_url(ranking.clone()).await.unwrap();
+        let requests = server_requests(&base).await;
+        assert_eq!(count(&requests, "GET /v1/user/detail"), 1);
+        assert_eq!(count(&requests, "GET /v1/illust/ranking"), 1);
+
+        // 关注后用户详情重新请求, 收藏后排行榜重新请求
+        follow_user(5, Restrict::Public).await.unwrap();
+        user_detail(5).await.unwrap();
+        add_bookmark(7, Restrict::Public).await.unwrap();
+        illust_from_url(ranking).await.unwrap();
+        let requests = server_requests(&base).await;
+        assert_eq!(count(&requests, "GET /v1/user/detail"), 2);
+        assert_eq!(count(&requests, "GET /v1/illust/ranking"), 2);
+
+        // TTL 为 0 的地址(如收藏列表)不写入缓存, 也就没有离线时的过期缓存
+        let bookmarks = user_bookmarks(1, Restrict::Public, None).await.unwrap();
+        assert!(!bookmarks.illusts.is_empty());
+        let url = format!("{base}/v1/user/bookmarks/illust?user_id=1&restrict=public");
+        assert!(server_requests(&base)
+            .await
+            .contains(&"GET /v1/user/bookmarks/illust?user_id=1&restrict=public".to_owned()));
+        assert!(crate::entities::response_cache::find("1", &url)
+            .await
+            .unwrap()
+            .is_none());
+    });
+}
+
+#[test]
+fn logout_clears_account_data() {
+    let _env = test_env();
+    block_on(async {
+        let base = start_server(Faults::default()).await;
+        assert!(login("code").await.unwrap());
+        user_detail(5).await.unwrap();
+        record_view(Illust {
+            id: 1,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        view_history::record("2", 2, "{}".to_owned(), String::new(), String::new(), String::new())
+            .await
+            .unwrap();
+
+        logout(true).await.unwrap();
+        assert!(view_history::find_page("1", 0, 10).await.unwrap().is_empty());
+        assert_eq!(view_history::find_page("2", 0, 10).await.unwrap().len(), 1);
+        // 响应缓存随账号一起删除, 重新登录后需要重新请求
+        assert!(login("code").await.unwrap());
+        user_detail(5).await.unwrap();
+        let requests = server_requests(&base).await;
+        assert_eq!(
+            requests
+                .iter()
+                .filter(|r| r.starts_with("GET /v1/user/detail"))
+                .count(),
+            2
+        );
+    });
+}
+
+#[test]
+fn concurrent_image_loads() {
+    let _env = test_env();
+    block_on(async {
+        let base = start_server(Faults {
+            latency_ms: 500,
+            ..Default::default()
+        })
+        .await;
+        let url = |id: i64| {
+            format!("https://i.pximg.net/img-master/img/2024/01/01/00/00/00/{id}_p0_master1200.jpg")
+        };
+        // 与 FLUTTER_RUST_BRIDGE_HANDLER 一样通过 AppAsyncRuntime 派发,
+        // 100 个缩略图请求, 每张图片 4 个
+        let runtime = AppAsyncRuntime;
+        let loads: Vec<_> = (0..100)
+            .map(|i| {
+                let url = url(2000 + i % 25);
+                runtime.spawn(load_pixiv_image_resized(url, 64, 64, "webp".to_owned()))
+            })
+            .collect();
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        // 图片请求进行中, 其他 api 不需要排队等待
+        let start = std::time::Instant::now();
+        assert!(runtime.spawn(get_response_cache_offline()).await.unwrap());
+        runtime.spawn(current_user()).await.unwrap().unwrap();
+        assert!(start.elapsed() < std::time::Duration::from_millis(300));
+        assert!(loads.iter().any(|load| !load.is_finished()));
+
+        for load in loads {
+            let path = load.await.unwrap().unwrap();
+            assert!(std::path::Path::new(&path).is_file());
+        }
+        // 同一张图片只下载一次
+        let downloads = server_requests(&base)
+            .await
+            .into_iter()
+            .filter(|r| r.starts_with("GET /img-master/"))
+            .count();
+        assert_eq!(downloads, 25);
+    });
+}
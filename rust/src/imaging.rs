@@ -0,0 +1,145 @@
+use anyhow::{Error, Result};
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, GenericImageView};
+use std::collections::HashMap;
+
+const JPEG_QUALITY: u8 = 85;
+const BLURHASH_COMPONENTS_X: u32 = 4;
+const BLURHASH_COMPONENTS_Y: u32 = 3;
+const PLACEHOLDER_SAMPLE_SIZE: u32 = 64;
+const PALETTE_SIZE: usize = 5;
+
+/// 缩略图的输出格式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ResizedFormat {
+    Webp,
+    Jpeg,
+}
+
+impl ResizedFormat {
+    pub(crate) fn parse(value: &str) -> Result<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "webp" => Ok(ResizedFormat::Webp),
+            "jpeg" | "jpg" => Ok(ResizedFormat::Jpeg),
+            other => Err(Error::msg(format!("unsupported image format: {}", other))),
+        }
+    }
+
+    pub(crate) fn ext(&self) -> &'static str {
+        match self {
+            ResizedFormat::Webp => "webp",
+            ResizedFormat::Jpeg => "jpg",
+        }
+    }
+}
+
+/// 将图片等比缩放到 max_w * max_h 以内（不放大），并编码为指定格式
+pub(crate) fn resize_image(
+    data: &[u8],
+    max_w: u32,
+    max_h: u32,
+    format: ResizedFormat,
+) -> Result<Vec<u8>> {
+    let img = image::load_from_memory(data)?;
+    let img = if img.width() > max_w || img.height() > max_h {
+        img.resize(max_w, max_h, FilterType::Lanczos3)
+    } else {
+        img
+    };
+    let mut buf = Vec::new();
+    match format {
+        // image 自带的 webp 编码器只支持无损, 且只接受 rgb8/rgba8
+        ResizedFormat::Webp => {
+            let img = if img.color().has_alpha() {
+                DynamicImage::ImageRgba8(img.to_rgba8())
+            } else {
+                DynamicImage::ImageRgb8(img.to_rgb8())
+            };
+            img.write_with_encoder(WebPEncoder::new_lossless(&mut buf))?;
+        }
+        // jpeg 不支持透明通道
+        ResizedFormat::Jpeg => {
+            let img = DynamicImage::ImageRgb8(img.to_rgb8());
+            img.write_with_encoder(JpegEncoder::new_with_quality(&mut buf, JPEG_QUALITY))?;
+        }
+    }
+    Ok(buf)
+}
+
+/// 计算图片的 dHash（64位差异哈希），对缩放、轻微裁剪和重新压缩不敏感
+pub(crate) fn dhash(data: &[u8]) -> Result<u64> {
+    let img = image::load_from_memory(data)?
+        .grayscale()
+        .resize_exact(9, 8, FilterType::Triangle)
+        .to_luma8();
+    let mut hash = 0u64;
+    for y in 0..8 {
+        for x in 0..8 {
+            hash <<= 1;
+            if img.get_pixel(x, y)[0] < img.get_pixel(x + 1, y)[0] {
+                hash |= 1;
+            }
+        }
+    }
+    Ok(hash)
+}
+
+/// 两个hash之间不同的位数
+pub(crate) fn hamming_distance(a: u64, b: u64) -> u32 {
+    (a ^ b).count_ones()
+}
+
+/// 图片的占位信息
+pub(crate) struct Placeholder {
+    pub blurhash: String,
+    /// 主色调, 0xAARRGGBB, 按占比从高到低
+    pub palette: Vec<u32>,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// 计算图片的 blurhash 和主色调
+pub(crate) fn placeholder(data: &[u8]) -> Result<Placeholder> {
+    let img = image::load_from_memory(data)?;
+    let (width, height) = img.dimensions();
+    // 在小图上计算即可, blurhash 本身只保留极低频信息
+    let small = img
+        .thumbnail(PLACEHOLDER_SAMPLE_SIZE, PLACEHOLDER_SAMPLE_SIZE)
+        .to_rgba8();
+    let blurhash = blurhash::encode(
+        BLURHASH_COMPONENTS_X,
+        BLURHASH_COMPONENTS_Y,
+        small.width(),
+        small.height(),
+        small.as_raw(),
+    )?;
+    // 每通道量化为4位后统计, 取出现最多的几组并求组内平均色
+    let mut buckets: HashMap<u16, (u32, u32, u32, u32)> = HashMap::new();
+    for pixel in small.pixels() {
+        let [r, g, b, a] = pixel.0;
+        if a < 128 {
+            continue;
+        }
+        let key = ((r as u16 >> 4) << 8) | ((g as u16 >> 4) << 4) | (b as u16 >> 4);
+        let bucket = buckets.entry(key).or_insert((0, 0, 0, 0));
+        bucket.0 += 1;
+        bucket.1 += r as u32;
+        bucket.2 += g as u32;
+        bucket.3 += b as u32;
+    }
+    let mut buckets: Vec<(u32, u32, u32, u32)> = buckets.into_values().collect();
+    buckets.sort_by_key(|b| std::cmp::Reverse(b.0));
+    let palette = buckets
+        .into_iter()
+        .take(PALETTE_SIZE)
+        .map(|(count, r, g, b)| 0xFF000000 | ((r / count) << 16) | ((g / count) << 8) | (b / count))
+        .collect();
+    Ok(Placeholder {
+        blurhash,
+        palette,
+        width,
+        height,
+    })
+}
@@ -1,14 +1,21 @@
 pub mod api;
 mod frb_generated;
 
+mod crypto;
 mod entities;
+mod imaging;
 mod local;
 mod udto;
 mod pixirust;
+#[cfg(test)]
+mod fake_server_tests;
 
 use crate::entities::init_databases;
 use crate::local::join_paths;
+use flutter_rust_bridge::for_generated::BaseAsyncRuntime;
+use flutter_rust_bridge::JoinHandle;
 use once_cell::sync::OnceCell;
+use std::future::Future;
 use std::path::Path;
 use std::sync::Mutex;
 use tokio::time::Duration;
@@ -24,6 +31,19 @@ lazy_static::lazy_static! {
         .max_blocking_threads(30).build().unwrap();
 }
 
+/// 使用 RUNTIME 代替 flutter_rust_bridge 默认创建的运行时, 见 api::FLUTTER_RUST_BRIDGE_HANDLER
+pub struct AppAsyncRuntime;
+
+impl BaseAsyncRuntime for AppAsyncRuntime {
+    fn spawn<F>(&self, future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        RUNTIME.spawn(future)
+    }
+}
+
 pub(crate) fn init_root(path: &str) {
     let mut lock = INIT_ED.lock().unwrap();
     if *lock {
@@ -54,3 +74,20 @@ pub(crate) fn get_root() -> &'static String {
 pub(crate) fn get_network_image_dir() -> &'static String {
     NETWORK_IMAGE_DIR.get().unwrap()
 }
+
+/// 测试共用的数据目录, 使用全局状态(TOKEN/CLIENT/数据库)的测试需要持有返回的锁依次执行
+#[cfg(test)]
+pub(crate) fn test_env() -> std::sync::MutexGuard<'static, ()> {
+    lazy_static::lazy_static! {
+        static ref TEST_LOCK: Mutex<()> = Mutex::new(());
+    }
+    let dir = std::env::temp_dir().join(format!("pansy-test-{}", std::process::id()));
+    init_root(dir.to_str().unwrap());
+    TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// 在 RUNTIME 上执行测试, 数据库连接是在 RUNTIME 上创建的
+#[cfg(test)]
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    RUNTIME.block_on(future)
+}
@@ -1,18 +1,32 @@
+use crate::crypto::{decrypt_string, encrypt_string, is_encrypted};
 use crate::entities::property::{
-    load_bool_property, load_i64_property, load_property, save_bool_property, save_i64_property,
-    save_property,
+    load_bool_property, load_i64_property, load_property, save_bool_property, save_property,
+    vacuum,
+};
+use crate::entities::{ranking_snapshot, response_cache};
+use crate::pixirust::backend::{BackendOptions, HttpRequest, ReqwestBackend};
+use crate::pixirust::bypass_tls::{bypass_tls_config, hosts_by_ip, probe, split_ips};
+use crate::pixirust::doh::DohResolver;
+use crate::pixirust::client::{AuthError, Client, OAuthError, ServerUrls};
+use crate::pixirust::entities::{Illust, IllustResponse, RankMode, Token};
+use crate::frb_generated::StreamSink;
+use crate::udto::{
+    AuthEvent, AuthEventKind, BypassSniIpStatus, ConnectionProfile, DohSettings, IllustFilter,
+    ProxySettings, ProxyTestResult, RankingDiff, RankingEntryDto, RankingMoveDto, RateLimitState,
+    UiAccount,
 };
-use crate::pixirust::client::Client;
-use crate::pixirust::entities::Token;
 use anyhow::{Result, Ok};
 use serde_derive::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::collections::hash_map::DefaultHasher;
+use std::future::Future;
 use std::hash::Hasher;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
 use tokio::sync::{Mutex, MutexGuard};
 use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
 
 #[allow(dead_code)]
 pub(crate) fn join_paths<P: AsRef<Path>>(paths: Vec<P>) -> String {
@@ -56,10 +70,17 @@ lazy_static::lazy_static! {
         }
     );
     static ref IN_CHINA: Mutex<bool> = Mutex::new(false);
-    static ref CLIENT: Arc<RwLock<Client>> = Arc::new(RwLock::new(Client::new()));
+    /// 请求时 clone 出当前的客户端后立即释放锁, 限流等待和请求过程中不持有锁
+    static ref CLIENT: RwLock<Arc<Client>> = RwLock::new(Arc::new(Client::new()));
     static ref BYPASS_SNI_INITED: Mutex<bool> = Mutex::new(false);
     static ref BYPASS_SNI: RwLock<bool> = RwLock::new(false);
     static ref BYPASS_SNI_HOSTS: RwLock<HashMap<String,String>> = RwLock::new(HashMap::new());
+    static ref BYPASS_SNI_HEALTH: RwLock<HashMap<String, BypassIpHealth>> = RwLock::new(HashMap::new());
+    static ref ILLUST_FILTER: RwLock<Option<IllustFilter>> = RwLock::new(None);
+    static ref AUTH_EVENT_SINK: std::sync::Mutex<Option<StreamSink<AuthEvent>>> = std::sync::Mutex::new(None);
+    static ref PENDING_AUTH_EVENTS: std::sync::Mutex<Vec<AuthEvent>> = std::sync::Mutex::new(vec![]);
+    static ref CANCEL_TOKENS: std::sync::Mutex<HashMap<String, CancelEntry>> = std::sync::Mutex::new(HashMap::new());
+    static ref FEED_CURSORS: std::sync::Mutex<HashMap<i64, Arc<Mutex<FeedCursor>>>> = std::sync::Mutex::new(HashMap::new());
     static ref HASH_LOCK: Vec<Mutex::<()>> = {
         let mut mutex_vec: Vec<Mutex::<()>>  = vec![];
         for _ in 0..16 {
@@ -69,33 +90,174 @@ lazy_static::lazy_static! {
     };
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct TokenPeriod {
     pub token: Token,
     pub created_time: i64,
 }
 
+/// 已登录的账号, 以 user id 区分
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct StoredAccounts {
+    current: String,
+    accounts: Vec<TokenPeriod>,
+}
+
+impl StoredAccounts {
+    fn find(&self, user_id: &str) -> Option<&TokenPeriod> {
+        self.accounts.iter().find(|a| a.token.user.id == user_id)
+    }
+
+    fn upsert(&mut self, period: TokenPeriod) {
+        match self
+            .accounts
+            .iter_mut()
+            .find(|a| a.token.user.id == period.token.user.id)
+        {
+            Some(a) => *a = period,
+            None => self.accounts.push(period),
+        }
+    }
+}
+
+/// 没有监听时最多保留的事件数量
+const MAX_PENDING_AUTH_EVENTS: usize = 16;
+
+pub(crate) fn set_auth_event_sink(sink: StreamSink<AuthEvent>) {
+    let mut current = AUTH_EVENT_SINK.lock().unwrap();
+    // 开始监听之前(例如启动时读取账号)产生的事件
+    for event in PENDING_AUTH_EVENTS.lock().unwrap().drain(..) {
+        let _ = sink.add(event);
+    }
+    *current = Some(sink);
+}
+
+fn emit_auth_event(event: impl Into<AuthEvent>) {
+    let event = event.into();
+    let mut sink = AUTH_EVENT_SINK.lock().unwrap();
+    if let Some(s) = sink.as_ref() {
+        if s.add(event).is_ok() {
+            return;
+        }
+        // dart端已取消监听
+        *sink = None;
+        return;
+    }
+    let mut pending = PENDING_AUTH_EVENTS.lock().unwrap();
+    if pending.len() == MAX_PENDING_AUTH_EVENTS {
+        pending.remove(0);
+    }
+    pending.push(event);
+}
+
 pub(crate) async fn hash_lock(url: &String) -> MutexGuard<'static, ()> {
     let mut s = DefaultHasher::new();
     s.write(url.as_bytes());
     HASH_LOCK[(s.finish() % 16) as usize].lock().await
 }
 
+/// 请求被 cancel 取消
+#[derive(Debug)]
+pub struct Cancelled;
+
+impl std::fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+/// 同一个 request_id 可以对应多个进行中的请求(例如一个页面的所有请求)
+struct CancelEntry {
+    generation: u64,
+    token: CancellationToken,
+    running: usize,
+}
+
+static CANCEL_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// 请求结束(或被丢弃)时从 CANCEL_TOKENS 中移除
+struct CancelGuard {
+    request_id: String,
+    generation: u64,
+}
+
+impl Drop for CancelGuard {
+    fn drop(&mut self) {
+        let mut tokens = CANCEL_TOKENS.lock().unwrap();
+        if let Some(entry) = tokens.get_mut(&self.request_id) {
+            // 取消后同一个id可能已经注册了新的请求
+            if entry.generation != self.generation {
+                return;
+            }
+            entry.running -= 1;
+            if entry.running == 0 {
+                tokens.remove(&self.request_id);
+            }
+        }
+    }
+}
+
+fn register_cancel(request_id: &str) -> (CancellationToken, CancelGuard) {
+    let mut tokens = CANCEL_TOKENS.lock().unwrap();
+    let entry = tokens
+        .entry(request_id.to_owned())
+        .or_insert_with(|| CancelEntry {
+            generation: CANCEL_GENERATION.fetch_add(1, Ordering::Relaxed),
+            token: CancellationToken::new(),
+            running: 0,
+        });
+    entry.running += 1;
+    (
+        entry.token.clone(),
+        CancelGuard {
+            request_id: request_id.to_owned(),
+            generation: entry.generation,
+        },
+    )
+}
+
+/// 执行可以被 cancel(request_id) 取消的请求
+///
+/// 取消时直接丢弃 future, 持有的 hash_lock / 限流等待随之释放
+pub(crate) async fn cancellable<T>(request_id: &str, f: impl Future<Output = Result<T>>) -> Result<T> {
+    let (token, _guard) = register_cancel(request_id);
+    tokio::select! {
+        result = f => result,
+        _ = token.cancelled() => Err(anyhow::Error::new(Cancelled)),
+    }
+}
+
+/// 取消 request_id 对应的所有进行中的请求, 返回是否有请求被取消
+pub(crate) fn cancel_(request_id: &str) -> bool {
+    match CANCEL_TOKENS.lock().unwrap().remove(request_id) {
+        Some(entry) => {
+            entry.token.cancel();
+            true
+        }
+        None => false,
+    }
+}
+
 /// 
 /// param: auth_level:
 ///   -1: no auth
 ///    0: no auth or auth fail is ok
 ///    1: ok at no auth, but error at auth fail
 ///    2: must authed success
-pub(crate) async fn client(auth_level: i32) -> Result<tokio::sync::RwLockReadGuard<'static, Client>> {
+pub(crate) async fn client(auth_level: i32) -> Result<Arc<Client>> {
     if -1 == auth_level {
-        return Ok(CLIENT.read().await);
+        return Ok(CLIENT.read().await.clone());
     }
     let period = TOKEN.lock().await;
     if period.created_time == 0 {
         if auth_level == 2 {
             return Err(anyhow::Error::msg("no authed"));
         }
+        // 未登录时没有可刷新的token
+        drop(period);
+        return Ok(CLIENT.read().await.clone());
     }
     match reload_auth(period).await {
         Err(err) => {
@@ -105,35 +267,159 @@ pub(crate) async fn client(auth_level: i32) -> Result<tokio::sync::RwLockReadGua
         }
         _ => {}
     }
-    Ok(CLIENT.read().await)
+    Ok(CLIENT.read().await.clone())
 }
 
-async fn reload_auth(mut period: tokio::sync::MutexGuard<'_, TokenPeriod>) -> Result<()> {
+/// 使用客户端发起请求, 如果服务端认为token无效(提前吊销、时钟偏差等), 刷新token后重试一次
+pub(crate) async fn with_auth_retry<T, F, Fut>(auth_level: i32, f: F) -> Result<T>
+where
+    F: Fn(Arc<Client>) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let client = client(auth_level).await?;
+    let used_access_token = client.access_token.clone();
+    match f(client).await {
+        Err(err) if err.downcast_ref::<AuthError>().is_some() => {
+            force_refresh(&used_access_token).await?;
+            f(self::client(auth_level).await?).await
+        }
+        other => other,
+    }
+}
+
+/// 提前刷新token的时间, 避免请求途中过期
+const TOKEN_REFRESH_MARGIN_MILLIS: i64 = 5 * 60 * 1000;
+
+async fn reload_auth(period: MutexGuard<'_, TokenPeriod>) -> Result<()> {
     let now = chrono::Local::now().timestamp_millis();
-    let mut check_lock = CLIENT.write().await;
-    if period.token.expires_in + period.created_time < now {
-        let new_token = check_lock
-            .refresh_token(&period.token.refresh_token)
-            .await?;
-        write_token(&new_token, &now).await;
-        period.created_time = now;
-        period.token = new_token;
-        (*check_lock).access_token = period.token.access_token.clone();
-    }
-    drop(check_lock);
+    if expires_soon(&period, now) {
+        refresh_period(period).await?;
+    }
     Ok(())
 }
 
-async fn write_token(token: &Token, time: &i64) {
+/// token已经过期或即将过期
+fn expires_soon(period: &TokenPeriod, now: i64) -> bool {
+    // expires_in 单位为秒, created_time 单位为毫秒
+    let expires_at = period.created_time + period.token.expires_in * 1000;
+    expires_at - TOKEN_REFRESH_MARGIN_MILLIS < now
+}
+
+/// 请求返回token无效时强制刷新, 如果其他请求已经刷新过则跳过
+async fn force_refresh(used_access_token: &str) -> Result<()> {
+    let period = TOKEN.lock().await;
+    if period.created_time == 0 {
+        return Err(anyhow::Error::msg("no authed"));
+    }
+    if period.token.access_token != used_access_token {
+        return Ok(());
+    }
+    refresh_period(period).await
+}
+
+async fn refresh_period(mut period: MutexGuard<'_, TokenPeriod>) -> Result<()> {
+    let now = chrono::Local::now().timestamp_millis();
+    let client = CLIENT.read().await.clone();
+    let refreshed = client.refresh_token(&period.token.refresh_token).await;
+    let new_token = match refreshed {
+        std::result::Result::Ok(new_token) => new_token,
+        Err(err) => {
+            // 网络错误(DNS、超时)和5xx是暂时的, 下次请求会再次刷新
+            let oauth_error = match err.downcast_ref::<OAuthError>() {
+                Some(e) if e.is_rejected() => e,
+                _ => return Err(err),
+            };
+            emit_auth_event(AuthEvent {
+                kind: AuthEventKind::RefreshFailed,
+                reason: err.to_string(),
+            });
+            // refresh token 已失效, 只能重新登录
+            if oauth_error.is_invalid_grant() {
+                clear_current_account(&mut period).await?;
+            }
+            return Err(err);
+        }
+    };
+    write_token(&new_token, &now).await?;
+    apply_period(
+        &mut period,
+        TokenPeriod {
+            token: new_token,
+            created_time: now,
+        },
+    )
+    .await;
+    emit_auth_event(AuthEventKind::TokenRefreshed);
+    Ok(())
+}
+
+async fn load_accounts() -> Result<StoredAccounts> {
+    let raw = load_property("accounts.json".to_owned()).await?;
+    if is_encrypted(&raw) {
+        return match decrypt_string(&raw) {
+            std::result::Result::Ok(json) => Ok(serde_json::from_str(&json)?),
+            Err(err) => {
+                // 密钥丢失或数据损坏, 只能重新登录; 原数据另存, 以免之后保存的账号覆盖它
+                let key = format!(
+                    "accounts.json.unreadable.{}",
+                    chrono::Local::now().timestamp_millis()
+                );
+                save_property(key.clone(), raw).await?;
+                save_property("accounts.json".to_owned(), String::default()).await?;
+                println!("Failed to decrypt accounts, moved to {} : {}", key, err);
+                emit_auth_event(AuthEvent {
+                    kind: AuthEventKind::AccountsUnreadable,
+                    reason: format!("{} ({})", err, key),
+                });
+                Ok(StoredAccounts::default())
+            }
+        };
+    }
+    if !raw.is_empty() {
+        // 明文保存的旧数据, 读取后加密保存
+        let accounts: StoredAccounts = serde_json::from_str(&raw)?;
+        save_accounts(&accounts).await?;
+        vacuum().await?;
+        return Ok(accounts);
+    }
+    // 旧版本只保存一个账号(token.json), 迁移到账号列表
+    let mut accounts = StoredAccounts::default();
+    let token_json_str = load_property("token.json".to_owned()).await?;
+    if !token_json_str.is_empty() {
+        let token: Token = serde_json::from_str(&token_json_str)?;
+        let time: i64 = load_i64_property("token_time".to_string()).await?;
+        accounts.current = token.user.id.clone();
+        accounts.upsert(TokenPeriod {
+            token,
+            created_time: time,
+        });
+        save_accounts(&accounts).await?;
+        save_property("token.json".to_owned(), String::default()).await?;
+        save_property("token_time".to_owned(), String::default()).await?;
+        vacuum().await?;
+    }
+    Ok(accounts)
+}
+
+/// token 加密后保存, 密钥见 crypto
+async fn save_accounts(accounts: &StoredAccounts) -> Result<()> {
     save_property(
-        "token.json".to_owned(),
-        serde_json::to_string(token).unwrap(),
+        "accounts.json".to_owned(),
+        encrypt_string(&serde_json::to_string(accounts)?)?,
     )
-    .await
-    .unwrap();
-    save_i64_property("token_time".to_owned(), time.clone())
-        .await
-        .unwrap();
+    .await?;
+    Ok(())
+}
+
+/// 保存token并设为当前账号, 调用时需持有 TOKEN 锁
+async fn write_token(token: &Token, time: &i64) -> Result<()> {
+    let mut accounts = load_accounts().await?;
+    accounts.current = token.user.id.clone();
+    accounts.upsert(TokenPeriod {
+        token: token.clone(),
+        created_time: *time,
+    });
+    save_accounts(&accounts).await
 }
 
 pub(crate) async fn load_in_china() {
@@ -142,27 +428,118 @@ pub(crate) async fn load_in_china() {
 }
 
 pub(crate) async fn load_token() -> Result<bool> {
-    let token_json_str = load_property("token.json".to_owned()).await?;
-    if token_json_str == "" {
-        return Ok(false);
-    }
-    let token: Token = serde_json::from_str(&token_json_str)?;
-    let time: i64 = load_i64_property("token_time".to_string()).await?;
-    // 读取完成
     let mut period = TOKEN.lock().await;
-    period.token = token;
-    period.created_time = time;
-    CLIENT.write().await.access_token = period.token.access_token.clone();
+    let accounts = load_accounts().await?;
+    let current = match accounts.find(&accounts.current) {
+        Some(current) => current.clone(),
+        None => return Ok(false),
+    };
+    // 读取完成
+    apply_period(&mut period, current).await;
     Ok(true)
 }
 
-pub(crate) async fn set_token(token: Token, time: i64) {
-    write_token(&token, &time).await;
+pub(crate) async fn set_token(token: Token, time: i64) -> Result<()> {
+    let mut period = TOKEN.lock().await;
+    write_token(&token, &time).await?;
     // 读取完成
+    apply_period(
+        &mut period,
+        TokenPeriod {
+            token,
+            created_time: time,
+        },
+    )
+    .await;
+    emit_auth_event(AuthEventKind::LoggedIn);
+    Ok(())
+}
+
+/// 替换内存中的token, 持有 TOKEN 锁时同时写入 CLIENT, 进行中的请求继续使用旧的token
+async fn apply_period(period: &mut MutexGuard<'_, TokenPeriod>, value: TokenPeriod) {
+    let mut client = CLIENT.write().await;
+    **period = value;
+    Arc::make_mut(&mut client).access_token = period.token.access_token.clone();
+}
+
+pub(crate) async fn list_accounts_() -> Result<Vec<UiAccount>> {
+    let _period = TOKEN.lock().await;
+    let accounts = load_accounts().await?;
+    Ok(accounts
+        .accounts
+        .iter()
+        .map(|a| UiAccount {
+            user_id: a.token.user.id.parse().unwrap_or(0),
+            name: a.token.user.name.clone(),
+            account: a.token.user.account.clone(),
+            profile_image_url: a.token.user.profile_image_urls.px_170x170.clone(),
+            is_premium: a.token.user.is_premium,
+            is_current: a.token.user.id == accounts.current,
+        })
+        .collect())
+}
+
+pub(crate) async fn switch_account_(user_id: i64) -> Result<()> {
+    let mut period = TOKEN.lock().await;
+    let mut accounts = load_accounts().await?;
+    let target = accounts
+        .find(&user_id.to_string())
+        .cloned()
+        .ok_or_else(|| anyhow::Error::msg("account not found"))?;
+    accounts.current = target.token.user.id.clone();
+    save_accounts(&accounts).await?;
+    apply_period(&mut period, target).await;
+    emit_auth_event(AuthEventKind::LoggedIn);
+    Ok(())
+}
+
+/// 退出当前账号: 删除保存的token并清空内存中的登录状态, 其他已登录的账号保留
+/// 当前账号的 user id, 未登录时为空
+pub(crate) async fn current_account_id() -> String {
+    TOKEN.lock().await.token.user.id.clone()
+}
+
+pub(crate) async fn logout_() -> Result<()> {
+    let mut period = TOKEN.lock().await;
+    clear_current_account(&mut period).await
+}
+
+/// 删除当前账号保存的token和响应缓存并变为未登录, 调用时需持有 TOKEN 锁
+async fn clear_current_account(period: &mut MutexGuard<'_, TokenPeriod>) -> Result<()> {
+    let mut accounts = load_accounts().await?;
+    let current = std::mem::take(&mut accounts.current);
+    accounts.accounts.retain(|a| a.token.user.id != current);
+    save_accounts(&accounts).await?;
+    apply_period(period, TokenPeriod::default()).await;
+    emit_auth_event(AuthEventKind::LoggedOut);
+    response_cache::delete_account(&current).await?;
+    Ok(())
+}
+
+/// 删除账号, 如果删除的是当前账号则切换到剩余的第一个账号(没有则变为未登录)
+///
+/// 同时删除该账号的响应缓存
+pub(crate) async fn remove_account_(user_id: i64) -> Result<()> {
     let mut period = TOKEN.lock().await;
-    period.token = token;
-    period.created_time = time;
-    CLIENT.write().await.access_token = period.token.access_token.clone();
+    let mut accounts = load_accounts().await?;
+    let user_id = user_id.to_string();
+    accounts.accounts.retain(|a| a.token.user.id != user_id);
+    if accounts.current == user_id {
+        let next = accounts.accounts.first().cloned().unwrap_or_default();
+        accounts.current = next.token.user.id.clone();
+        save_accounts(&accounts).await?;
+        let logged_in = next.created_time != 0;
+        apply_period(&mut period, next).await;
+        emit_auth_event(if logged_in {
+            AuthEventKind::LoggedIn
+        } else {
+            AuthEventKind::LoggedOut
+        });
+    } else {
+        save_accounts(&accounts).await?;
+    }
+    response_cache::delete_account(&user_id).await?;
+    Ok(())
 }
 
 pub(crate) async fn get_in_china_() -> bool {
@@ -178,12 +555,28 @@ pub(crate) async fn set_in_china_(value: bool) {
     // Do not change network behavior automatically; "China mode" is only a user preference flag.
 }
 
+const BYPASS_SNI_PROBE_INTERVAL_SECS: u64 = 600;
+/// DoH 刷新后每个 host 最多保留的候选IP数量
+const BYPASS_SNI_MAX_IPS: usize = 4;
+
+/// 候选IP最近一次的检查结果
+#[derive(Debug, Clone, Default)]
+struct BypassIpHealth {
+    healthy: bool,
+    tcp_ms: i64,
+    tls_ms: i64,
+    error: String,
+    /// 0 表示还没有检查过
+    checked_time: i64,
+}
+
+/// 每个 host 可以有多个候选IP, 以逗号分隔, 按健康检查的结果选择
 fn default_bypass_sni_hosts() -> HashMap<String, String> {
     HashMap::from([
-        ("app-api.pixiv.net".to_owned(), "210.140.139.155".to_owned()),
-        ("oauth.secure.pixiv.net".to_owned(), "210.140.139.155".to_owned()),
-        ("i.pximg.net".to_owned(), "210.140.139.133".to_owned()),
-        ("s.pximg.net".to_owned(), "210.140.139.133".to_owned()),
+        ("app-api.pixiv.net".to_owned(), "210.140.139.155,210.140.131.199".to_owned()),
+        ("oauth.secure.pixiv.net".to_owned(), "210.140.139.155,210.140.131.199".to_owned()),
+        ("i.pximg.net".to_owned(), "210.140.139.133,210.140.92.143".to_owned()),
+        ("s.pximg.net".to_owned(), "210.140.139.133,210.140.92.140".to_owned()),
     ])
 }
 
@@ -222,22 +615,30 @@ pub(crate) async fn init_bypass_sni_settings() {
 
 pub(crate) async fn set_bypass_sni_cache(value: bool) {
     *BYPASS_SNI.write().await = value;
+    if value {
+        spawn_bypass_sni_probe();
+    }
 }
 
 pub(crate) async fn set_bypass_sni_hosts_cache(value: HashMap<String, String>) {
     let mut normalized: HashMap<String, String> = HashMap::new();
     for (k, v) in value {
         let kk = k.trim();
-        let vv = v.trim();
+        let vv = split_ips(&v).join(",");
         if kk.is_empty() || vv.is_empty() {
             continue;
         }
-        normalized.insert(kk.to_owned(), vv.to_owned());
+        normalized.insert(kk.to_owned(), vv);
     }
     if normalized.is_empty() {
         normalized = default_bypass_sni_hosts();
     }
     *BYPASS_SNI_HOSTS.write().await = normalized;
+    // 证书校验依赖 ip -> host, 需要重建
+    if let Err(err) = rebuild_backend().await {
+        println!("Failed to rebuild network client : {}", err);
+    }
+    spawn_bypass_sni_probe();
 }
 
 pub(crate) async fn get_bypass_sni_() -> bool {
@@ -245,13 +646,1449 @@ pub(crate) async fn get_bypass_sni_() -> bool {
     *BYPASS_SNI.read().await
 }
 
-pub(crate) async fn get_bypass_sni_ip_for_host(host: &str) -> Option<String> {
+/// 候选IP按健康状况排序: 可用的按耗时从低到高, 然后是未检查的, 最后是不可用的
+pub(crate) async fn get_bypass_sni_ips_for_host(host: &str) -> Vec<String> {
+    init_bypass_sni_settings().await;
+    let mut ips = match BYPASS_SNI_HOSTS.read().await.get(host) {
+        Some(v) => split_ips(v),
+        None => vec![],
+    };
+    if ips.is_empty() {
+        ips = default_bypass_sni_hosts()
+            .get(host)
+            .map(|v| split_ips(v))
+            .unwrap_or_default();
+    }
+    let health = BYPASS_SNI_HEALTH.read().await;
+    ips.sort_by_key(|ip| match health.get(ip) {
+        Some(h) if h.healthy => (0, h.tcp_ms + h.tls_ms),
+        Some(_) => (2, 0),
+        None => (1, 0),
+    });
+    ips
+}
+
+/// 请求时连接失败, 标记为不可用, 下次探测成功后恢复
+pub(crate) async fn report_bypass_sni_failure(ip: &str, error: &str) {
+    BYPASS_SNI_HEALTH.write().await.insert(
+        ip.to_owned(),
+        BypassIpHealth {
+            healthy: false,
+            tcp_ms: -1,
+            tls_ms: -1,
+            error: error.to_owned(),
+            checked_time: chrono::Local::now().timestamp_millis(),
+        },
+    );
+}
+
+/// 探测映射中所有IP的 TCP 连接和 TLS 握手耗时
+pub(crate) async fn probe_bypass_sni_ips() -> Result<()> {
+    init_bypass_sni_settings().await;
+    let hosts = BYPASS_SNI_HOSTS.read().await.clone();
+    let config = Arc::new(bypass_tls_config(false)?);
+    // 同一个IP的多个域名使用同一张证书, 用其中一个域名握手即可
+    let tasks: Vec<_> = hosts_by_ip(&hosts)
+        .into_iter()
+        .map(|(ip, hosts)| {
+            let config = config.clone();
+            tokio::spawn(async move {
+                let ip = ip.to_string();
+                let result = probe(config, &hosts[0], &ip).await;
+                (ip, result)
+            })
+        })
+        .collect();
+    for task in tasks {
+        let (ip, result) = task.await?;
+        let health = match result {
+            std::result::Result::Ok(result) => BypassIpHealth {
+                healthy: true,
+                tcp_ms: result.tcp_ms,
+                tls_ms: result.tls_ms,
+                error: String::default(),
+                checked_time: chrono::Local::now().timestamp_millis(),
+            },
+            Err(err) => BypassIpHealth {
+                healthy: false,
+                tcp_ms: -1,
+                tls_ms: -1,
+                error: err.to_string(),
+                checked_time: chrono::Local::now().timestamp_millis(),
+            },
+        };
+        BYPASS_SNI_HEALTH.write().await.insert(ip, health);
+    }
+    Ok(())
+}
+
+fn spawn_bypass_sni_probe() {
+    tokio::spawn(async {
+        if !get_bypass_sni_().await {
+            return;
+        }
+        if let Err(err) = probe_bypass_sni_ips().await {
+            println!("Failed to probe bypass sni ips : {}", err);
+        }
+    });
+}
+
+/// 启动后台任务, 开启 SNI bypass 时定时探测候选IP
+pub(crate) fn start_bypass_sni_probe_loop() {
+    tokio::spawn(async {
+        loop {
+            spawn_bypass_sni_probe();
+            tokio::time::sleep(std::time::Duration::from_secs(BYPASS_SNI_PROBE_INTERVAL_SECS))
+                .await;
+        }
+    });
+}
+
+/// 立即探测一次, 返回每个 host 的每个候选IP的状态
+pub(crate) async fn bypass_sni_diagnostics_() -> Result<Vec<BypassSniIpStatus>> {
+    probe_bypass_sni_ips().await?;
+    let mut hosts: Vec<String> = BYPASS_SNI_HOSTS.read().await.keys().cloned().collect();
+    hosts.sort();
+    let mut result = vec![];
+    for host in hosts {
+        let ips = get_bypass_sni_ips_for_host(&host).await;
+        let health = BYPASS_SNI_HEALTH.read().await;
+        for (idx, ip) in ips.into_iter().enumerate() {
+            let h = health.get(&ip).cloned().unwrap_or_default();
+            result.push(BypassSniIpStatus {
+                host: host.clone(),
+                ip,
+                selected: idx == 0,
+                checked: h.checked_time > 0,
+                healthy: h.healthy,
+                tcp_ms: h.tcp_ms,
+                tls_ms: h.tls_ms,
+                error: h.error,
+                checked_time: h.checked_time,
+            });
+        }
+    }
+    Ok(result)
+}
+
+pub(crate) async fn get_illust_filter_() -> IllustFilter {
+    if let Some(filter) = ILLUST_FILTER.read().await.as_ref() {
+        return filter.clone();
+    }
+    let raw = load_property("illust_filter".to_owned()).await.unwrap_or_default();
+    let filter: IllustFilter = serde_json::from_str(&raw).unwrap_or_default();
+    *ILLUST_FILTER.write().await = Some(filter.clone());
+    filter
+}
+
+pub(crate) async fn set_illust_filter_(mut filter: IllustFilter) -> Result<()> {
+    let mut tags: Vec<String> = vec![];
+    for tag in filter.muted_tags {
+        let tag = tag.trim();
+        if !tag.is_empty() && !tags.iter().any(|t| t == tag) {
+            tags.push(tag.to_owned());
+        }
+    }
+    filter.muted_tags = tags;
+    filter.muted_user_ids.sort();
+    filter.muted_user_ids.dedup();
+    save_property("illust_filter".to_owned(), serde_json::to_string(&filter)?).await?;
+    *ILLUST_FILTER.write().await = Some(filter);
+    Ok(())
+}
+
+fn illust_is_filtered(filter: &IllustFilter, illust: &Illust) -> bool {
+    if filter.hide_ai && illust.illust_ai_type == 2 {
+        return true;
+    }
+    if filter.hide_r18 && illust.x_restrict > 0 {
+        return true;
+    }
+    if filter.hide_ugoira && illust.illust_type == "ugoira" {
+        return true;
+    }
+    if filter.muted_user_ids.contains(&illust.user.id) {
+        return true;
+    }
+    illust.tags.iter().any(|tag| {
+        filter.muted_tags.iter().any(|muted| {
+            tag.name.eq_ignore_ascii_case(muted)
+                || tag
+                    .translated_name
+                    .as_ref()
+                    .is_some_and(|t| t.eq_ignore_ascii_case(muted))
+        })
+    })
+}
+
+/// 按本地屏蔽规则过滤作品, 并记录隐藏的数量
+pub(crate) async fn filter_illust_response(mut response: IllustResponse) -> IllustResponse {
+    let filter = get_illust_filter_().await;
+    let before = response.illusts.len();
+    response
+        .illusts
+        .retain(|illust| !illust_is_filtered(&filter, illust));
+    response.hidden_count = (before - response.illusts.len()) as i64;
+    response
+}
+
+const SERVER_APP_KEY: &str = "server_app";
+const SERVER_OAUTH_KEY: &str = "server_oauth";
+/// 与 Dart 的图片站点设置共用同一个key
+const PICTURE_SOURCE_KEY: &str = "picture_source";
+
+pub(crate) fn is_server_property(key: &str) -> bool {
+    key == SERVER_APP_KEY || key == SERVER_OAUTH_KEY || key == PICTURE_SOURCE_KEY
+}
+
+/// 规范化服务地址, 空值使用默认地址, 只写了host时补全为https
+fn normalize_server_url(value: &str, default: &str) -> Result<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(default.to_owned());
+    }
+    let url = if value.contains("://") {
+        value.to_owned()
+    } else {
+        format!("https://{}", value)
+    };
+    let parsed = reqwest::Url::parse(&url)?;
+    if parsed.scheme() != "http" && parsed.scheme() != "https" {
+        return Err(anyhow::Error::msg(format!("unsupported server url: {}", value)));
+    }
+    Ok(url.trim_end_matches('/').to_owned())
+}
+
+/// 从配置中读取服务地址并应用到客户端, 配置有误的项使用默认地址
+pub(crate) async fn load_server_urls() {
+    let defaults = ServerUrls::default();
+    let mut servers = ServerUrls::default();
+    for (key, default, target) in [
+        (SERVER_APP_KEY, &defaults.app, &mut servers.app),
+        (SERVER_OAUTH_KEY, &defaults.oauth, &mut servers.oauth),
+        (PICTURE_SOURCE_KEY, &defaults.image, &mut servers.image),
+    ] {
+        let value = load_property(key.to_owned()).await.unwrap_or_default();
+        *target = normalize_server_url(&value, default).unwrap_or_else(|_| default.clone());
+    }
+    Arc::make_mut(&mut *CLIENT.write().await).set_servers(servers);
+}
+
+/// 修改并保存 app-api / oauth / 图片服务的地址, 例如镜像站或本地测试服务(fake_server)
+pub(crate) async fn set_server_urls_(servers: ServerUrls) -> Result<()> {
+    let defaults = ServerUrls::default();
+    // 先全部校验, 保存用户填写的原始值(Dart 设置页按原值展示)
+    for (value, default) in [
+        (&servers.app, &defaults.app),
+        (&servers.oauth, &defaults.oauth),
+        (&servers.image, &defaults.image),
+    ] {
+        normalize_server_url(value, default)?;
+    }
+    save_property(SERVER_APP_KEY.to_owned(), servers.app.trim().to_owned()).await?;
+    save_property(SERVER_OAUTH_KEY.to_owned(), servers.oauth.trim().to_owned()).await?;
+    save_property(PICTURE_SOURCE_KEY.to_owned(), servers.image.trim().to_owned()).await?;
+    load_server_urls().await;
+    Ok(())
+}
+
+pub(crate) async fn get_server_urls_() -> ServerUrls {
+    CLIENT.read().await.servers().clone()
+}
+
+const PROXY_SETTINGS_KEY: &str = "proxy_settings";
+const PROXY_TEST_TIMEOUT_SECS: u64 = 15;
+
+/// 读取代理设置, 可能包含密码, 所以加密保存
+pub(crate) async fn get_proxy_settings_() -> ProxySettings {
+    let raw = load_property(PROXY_SETTINGS_KEY.to_owned())
+        .await
+        .unwrap_or_default();
+    if raw.is_empty() {
+        return ProxySettings::default();
+    }
+    match decrypt_string(&raw) {
+        std::result::Result::Ok(json) => serde_json::from_str(&json).unwrap_or_default(),
+        Err(err) => {
+            println!("Failed to decrypt proxy settings : {}", err);
+            ProxySettings::default()
+        }
+    }
+}
+
+fn build_proxy(settings: &ProxySettings) -> Result<Option<reqwest::Proxy>> {
+    let url = settings.url.trim();
+    if url.is_empty() {
+        return Ok(None);
+    }
+    let parsed = reqwest::Url::parse(url)?;
+    if !["http", "https", "socks5", "socks5h"].contains(&parsed.scheme()) {
+        return Err(anyhow::Error::msg(format!("unsupported proxy url: {}", url)));
+    }
+    let mut proxy = reqwest::Proxy::all(url)?;
+    if !settings.username.is_empty() {
+        proxy = proxy.basic_auth(&settings.username, &settings.password);
+    }
+    proxy = proxy.no_proxy(reqwest::NoProxy::from_string(&settings.no_proxy));
+    Ok(Some(proxy))
+}
+
+/// 按代理 / DoH / SNI bypass 设置重建客户端的传输层
+async fn rebuild_backend() -> Result<()> {
+    init_bypass_sni_settings().await;
+    let doh = get_doh_settings_().await;
+    let proxy = build_proxy(&get_proxy_settings_().await)?;
+    let backend = ReqwestBackend::with_options(BackendOptions {
+        resolver: if doh.enabled {
+            Some(DohResolver::new(doh.endpoints, proxy.clone())?)
+        } else {
+            None
+        },
+        proxy,
+        bypass_hosts: BYPASS_SNI_HOSTS.read().await.clone(),
+        profile: get_connection_profile_().await,
+    })?;
+    Arc::make_mut(&mut *CLIENT.write().await).set_backend(Box::new(backend));
+    Ok(())
+}
+
+/// 应用网络设置, 并按需在后台用 DoH 刷新 SNI bypass 的IP
+pub(crate) async fn apply_network_settings() -> Result<()> {
+    rebuild_backend().await?;
+    if get_doh_settings_().await.update_bypass_hosts {
+        tokio::spawn(async {
+            if let Err(err) = refresh_bypass_sni_hosts_().await {
+                println!("Failed to refresh bypass sni hosts : {}", err);
+            }
+        });
+    }
+    Ok(())
+}
+
+pub(crate) async fn set_proxy_settings_(mut settings: ProxySettings) -> Result<()> {
+    settings.url = settings.url.trim().to_owned();
+    settings.username = settings.username.trim().to_owned();
+    settings.no_proxy = settings.no_proxy.trim().to_owned();
+    // 保存前先确认可以构建
+    build_proxy(&settings)?;
+    save_property(
+        PROXY_SETTINGS_KEY.to_owned(),
+        encrypt_string(&serde_json::to_string(&settings)?)?,
+    )
+    .await?;
+    apply_network_settings().await
+}
+
+/// 使用当前网络设置依次请求 app-api / oauth / 图片服务, 记录耗时
+pub(crate) async fn test_proxy_() -> Vec<ProxyTestResult> {
+    // 探测可能要等待超时, 不持有客户端的锁, 以免阻塞修改设置
+    let client = CLIENT.read().await.clone();
+    let (servers, backend) = (client.servers().clone(), client.backend());
+    let mut results = vec![];
+    for url in [servers.app, servers.oauth, servers.image] {
+        let start = std::time::Instant::now();
+        // 不带token请求一次地址
+        let rsp = tokio::time::timeout(
+            std::time::Duration::from_secs(PROXY_TEST_TIMEOUT_SECS),
+            backend.send(HttpRequest::new(reqwest::Method::GET, url.clone())),
+        )
+        .await;
+        // 能收到响应即视为连通, 不关心状态码
+        results.push(match rsp {
+            std::result::Result::Ok(std::result::Result::Ok(rsp)) => ProxyTestResult {
+                url,
+                latency_ms: start.elapsed().as_millis() as i64,
+                status: rsp.status as i32,
+                error: String::default(),
+            },
+            std::result::Result::Ok(Err(err)) => ProxyTestResult {
+                url,
+                latency_ms: -1,
+                status: 0,
+                error: err.to_string(),
+            },
+            Err(_) => ProxyTestResult {
+                url,
+                latency_ms: -1,
+                status: 0,
+                error: "timeout".to_owned(),
+            },
+        });
+    }
+    results
+}
+
+const DOH_SETTINGS_KEY: &str = "doh_settings";
+
+pub(crate) async fn get_doh_settings_() -> DohSettings {
+    let raw = load_property(DOH_SETTINGS_KEY.to_owned())
+        .await
+        .unwrap_or_default();
+    serde_json::from_str(&raw).unwrap_or_default()
+}
+
+pub(crate) async fn set_doh_settings_(mut settings: DohSettings) -> Result<()> {
+    let mut endpoints = vec![];
+    for endpoint in settings.endpoints {
+        let endpoint = endpoint.trim();
+        if endpoint.is_empty() {
+            continue;
+        }
+        if reqwest::Url::parse(endpoint)?.scheme() != "https" {
+            return Err(anyhow::Error::msg(format!("doh endpoint must be https: {}", endpoint)));
+        }
+        endpoints.push(endpoint.to_owned());
+    }
+    if endpoints.is_empty() {
+        endpoints = DohSettings::default().endpoints;
+    }
+    settings.endpoints = endpoints;
+    save_property(DOH_SETTINGS_KEY.to_owned(), serde_json::to_string(&settings)?).await?;
+    apply_network_settings().await
+}
+
+/// 使用 DoH 重新解析 SNI bypass 的各个host, 保存解析成功的IP
+pub(crate) async fn refresh_bypass_sni_hosts_() -> Result<HashMap<String, String>> {
     init_bypass_sni_settings().await;
-    if let Some(v) = BYPASS_SNI_HOSTS.read().await.get(host) {
-        let vv = v.trim();
-        if !vv.is_empty() {
-            return Some(vv.to_owned());
+    let resolver = DohResolver::new(
+        get_doh_settings_().await.endpoints,
+        build_proxy(&get_proxy_settings_().await)?,
+    )?;
+    let mut hosts = BYPASS_SNI_HOSTS.read().await.clone();
+    for (host, value) in hosts.iter_mut() {
+        match resolver.lookup(host).await {
+            std::result::Result::Ok(resolved) => {
+                // 解析结果在前, 保留原有的候选IP作为备用
+                let mut ips: Vec<String> = resolved.iter().map(|ip| ip.to_string()).collect();
+                for ip in split_ips(value) {
+                    if !ips.contains(&ip) {
+                        ips.push(ip);
+                    }
+                }
+                ips.truncate(BYPASS_SNI_MAX_IPS);
+                *value = ips.join(",");
+            }
+            Err(err) => println!("Failed to resolve {} : {}", host, err),
         }
     }
-    default_bypass_sni_hosts().get(host).cloned()
+    save_property(
+        "bypass_sni_hosts".to_owned(),
+        serde_json::to_string(&hosts)?,
+    )
+    .await?;
+    set_bypass_sni_hosts_cache(hosts.clone()).await;
+    Ok(hosts)
+}
+
+const CONNECTION_PROFILE_KEY: &str = "connection_profile";
+
+pub(crate) async fn get_connection_profile_() -> ConnectionProfile {
+    let raw = load_property(CONNECTION_PROFILE_KEY.to_owned())
+        .await
+        .unwrap_or_default();
+    serde_json::from_str(&raw).unwrap_or_default()
+}
+
+pub(crate) async fn set_connection_profile_(profile: ConnectionProfile) -> Result<()> {
+    if profile.connect_timeout_secs == 0 || profile.read_timeout_secs == 0 {
+        return Err(anyhow::Error::msg("timeout must be greater than 0"));
+    }
+    save_property(
+        CONNECTION_PROFILE_KEY.to_owned(),
+        serde_json::to_string(&profile)?,
+    )
+    .await?;
+    rebuild_backend().await
+}
+
+pub(crate) async fn rate_limit_states_() -> Vec<RateLimitState> {
+    CLIENT.read().await.rate_limit_states()
+}
+
+const RESPONSE_CACHE_OFFLINE_KEY: &str = "response_cache_offline";
+
+/// app-api 响应的缓存时间(毫秒), 离线时可以使用过期的缓存
+///
+/// 0 表示不缓存, 这些地址(收藏、搜索、关注列表等)网络失败时也没有过期缓存可用
+fn response_cache_ttl(url: &str) -> i64 {
+    const MINUTE: i64 = 60 * 1000;
+    if url.contains("/illust/ranking") {
+        24 * 60 * MINUTE
+    } else if url.contains("/illust/recommended") {
+        5 * MINUTE
+    } else if url.contains("/user/detail") || url.contains("/trending-tags/") {
+        60 * MINUTE
+    } else {
+        0
+    }
+}
+
+/// 网络失败时是否使用过期的缓存, 默认开启
+pub(crate) async fn get_response_cache_offline_() -> bool {
+    let value = load_property(RESPONSE_CACHE_OFFLINE_KEY.to_owned())
+        .await
+        .unwrap_or_default();
+    value.is_empty() || value.parse().unwrap_or(true)
+}
+
+pub(crate) async fn set_response_cache_offline_(value: bool) -> Result<()> {
+    save_bool_property(RESPONSE_CACHE_OFFLINE_KEY.to_owned(), value).await?;
+    Ok(())
+}
+
+/// 请求 app-api 并缓存响应, 缓存按账号区分
+/// 返回 (响应, 是否为网络失败时使用的过期缓存)
+pub(crate) async fn cached_get_text(auth_level: i32, url: String) -> Result<(String, bool)> {
+    let account = current_account_id().await;
+    let ttl = response_cache_ttl(&url);
+    let cached = if ttl > 0 {
+        response_cache::find(&account, &url).await?
+    } else {
+        None
+    };
+    if let Some(cached) = &cached {
+        if chrono::Local::now().timestamp_millis() - cached.created_time < ttl {
+            return Ok((cached.body.clone(), false));
+        }
+    }
+    let fetched = with_auth_retry(auth_level, |client| {
+        let url = url.clone();
+        async move { client.get_from_pixiv_raw(url).await }
+    })
+    .await;
+    match fetched {
+        std::result::Result::Ok(body) => {
+            if ttl > 0 {
+                if let Err(err) = response_cache::save(&account, &url, body.clone()).await {
+                    println!("Failed to save response cache : {}", err);
+                }
+            }
+            Ok((body, false))
+        }
+        Err(err) => match cached {
+            // 只有网络错误时使用缓存, 服务端返回的错误照常抛出
+            Some(cached)
+                if err.downcast_ref::<reqwest::Error>().is_some()
+                    && get_response_cache_offline_().await =>
+            {
+                Ok((cached.body, true))
+            }
+            _ => Err(err),
+        },
+    }
+}
+
+pub(crate) async fn cached_get<T: for<'de> serde::Deserialize<'de>>(
+    auth_level: i32,
+    url: String,
+) -> Result<(T, bool)> {
+    let (body, stale) = cached_get_text(auth_level, url).await?;
+    Ok((serde_json::from_str(&body)?, stale))
+}
+
+/// 删除当前账号受影响的缓存, 失败时只打印日志(操作本身已经成功)
+async fn evict_response_cache(urls: Vec<String>, fragments: &[&str]) {
+    let account = current_account_id().await;
+    if let Err(err) = response_cache::delete_urls(&account, urls, fragments).await {
+        println!("Failed to evict response cache : {}", err);
+    }
+}
+
+/// 关注状态变化后, 用户详情和作品列表中的 is_followed 已过期
+pub(crate) async fn evict_follow_cache(user_id: i64) {
+    let user_detail_url = CLIENT.read().await.user_detail_url(user_id);
+    evict_response_cache(
+        vec![user_detail_url],
+        &["/illust/ranking", "/illust/recommended"],
+    )
+    .await
+}
+
+/// 收藏变化后, 缓存的作品列表中的 is_bookmarked 已过期 (收藏列表本身不缓存)
+pub(crate) async fn evict_bookmark_cache() {
+    evict_response_cache(
+        vec![],
+        &["/illust/ranking", "/illust/recommended"],
+    )
+    .await
+}
+
+pub(crate) async fn clear_response_cache_() -> Result<()> {
+    response_cache::delete_all().await?;
+    Ok(())
+}
+
+/// 加载一页作品(使用响应缓存), 并按本地屏蔽规则过滤
+pub(crate) async fn load_illust_page(url: String) -> Result<IllustResponse> {
+    let (mut illust, stale): (IllustResponse, bool) = cached_get(2, url).await?;
+    illust.stale = stale;
+    Ok(filter_illust_response(illust).await)
+}
+
+/// 分页读取作品列表, 自己保存 next_url, 并去除不同页之间重复的作品
+pub(crate) struct FeedCursor {
+    next_url: Option<String>,
+    seen: HashSet<i64>,
+    /// cursor_collect_all 超出 limit 的作品, 下一次读取时先返回
+    pending: Vec<Illust>,
+}
+
+impl FeedCursor {
+    fn new(first_url: String) -> Self {
+        Self {
+            next_url: Some(first_url),
+            seen: HashSet::new(),
+            pending: vec![],
+        }
+    }
+
+    /// 读取下一页, 没有更多时返回 None
+    async fn next(&mut self) -> Result<Option<IllustResponse>> {
+        if !self.pending.is_empty() {
+            return Ok(Some(IllustResponse {
+                illusts: std::mem::take(&mut self.pending),
+                next_url: self.next_url.clone(),
+                ..Default::default()
+            }));
+        }
+        let url = match &self.next_url {
+            Some(url) => url.clone(),
+            None => return Ok(None),
+        };
+        let page = load_illust_page(url).await?;
+        Ok(Some(self.accept(page)))
+    }
+
+    /// 记录下一页的地址, 去掉之前的页已经返回过的作品
+    fn accept(&mut self, mut page: IllustResponse) -> IllustResponse {
+        page.illusts.retain(|illust| self.seen.insert(illust.id));
+        self.next_url = page.next_url.clone();
+        page
+    }
+}
+
+static FEED_CURSOR_ID: AtomicI64 = AtomicI64::new(1);
+
+fn feed_cursor(handle: i64) -> Result<Arc<Mutex<FeedCursor>>> {
+    FEED_CURSORS
+        .lock()
+        .unwrap()
+        .get(&handle)
+        .cloned()
+        .ok_or_else(|| anyhow::Error::msg("feed cursor not found"))
+}
+
+/// 从任意列表的第一页url(推荐 / 排行 / 搜索 / 收藏 / 用户作品)创建游标, 返回句柄
+pub(crate) fn cursor_open_(first_url: String) -> i64 {
+    let handle = FEED_CURSOR_ID.fetch_add(1, Ordering::Relaxed);
+    FEED_CURSORS
+        .lock()
+        .unwrap()
+        .insert(handle, Arc::new(Mutex::new(FeedCursor::new(first_url))));
+    handle
+}
+
+pub(crate) async fn cursor_next_(handle: i64) -> Result<Option<IllustResponse>> {
+    let cursor = feed_cursor(handle)?;
+    let mut cursor = cursor.lock().await;
+    cursor.next().await
+}
+
+/// 依次发送剩余的页, 最多 max_pages 页, dart 端取消监听、游标关闭或没有更多时提前结束
+pub(crate) async fn cursor_stream_(
+    handle: i64,
+    max_pages: i64,
+    sink: StreamSink<IllustResponse>,
+) -> Result<()> {
+    cursor_send_pages(handle, max_pages, |page| sink.add(page).is_ok()).await
+}
+
+/// 每读取一页释放一次游标, 发送期间也可以调用 cursor_next 等; send 返回 false 时结束
+async fn cursor_send_pages(
+    handle: i64,
+    max_pages: i64,
+    mut send: impl FnMut(IllustResponse) -> bool,
+) -> Result<()> {
+    if max_pages <= 0 {
+        return Err(anyhow::Error::msg("max_pages must be greater than 0"));
+    }
+    for _ in 0..max_pages {
+        let cursor = match feed_cursor(handle) {
+            std::result::Result::Ok(cursor) => cursor,
+            Err(_) => break,
+        };
+        let page = cursor.lock().await.next().await?;
+        let sent = match page {
+            Some(page) => send(page),
+            None => false,
+        };
+        if !sent {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// 读取剩余的页直到没有更多或达到 limit 个作品, 用于导出和批量任务
+pub(crate) async fn cursor_collect_all_(handle: i64, limit: i64) -> Result<Vec<Illust>> {
+    if limit <= 0 {
+        return Err(anyhow::Error::msg("limit must be greater than 0"));
+    }
+    let limit = limit as usize;
+    feed_cursor(handle)?;
+    let mut illusts = vec![];
+    // 与 cursor_send_pages 相同, 每读取一页释放一次游标, 游标关闭后返回已读取的作品
+    while illusts.len() < limit {
+        let cursor = match feed_cursor(handle) {
+            std::result::Result::Ok(cursor) => cursor,
+            Err(_) => break,
+        };
+        let mut cursor = cursor.lock().await;
+        match cursor.next().await? {
+            Some(page) => illusts.extend(page.illusts),
+            None => break,
+        }
+        // 超出的部分留给下一次读取
+        if illusts.len() > limit {
+            cursor.pending = illusts.split_off(limit);
+        }
+    }
+    Ok(illusts)
+}
+
+pub(crate) fn cursor_close_(handle: i64) {
+    FEED_CURSORS.lock().unwrap().remove(&handle);
+}
+
+const RANKING_CAPTURE_MODES_KEY: &str = "ranking_capture_modes";
+const RANKING_CAPTURE_INTERVAL_SECS: u64 = 60 * 60;
+/// 排行榜最多500名, 每页30个
+const RANKING_CAPTURE_MAX_PAGES: usize = 20;
+
+/// 最新一期已公布的日榜日期, 排行榜在日本时间每天中午更新前一天的数据
+pub(crate) fn latest_ranking_date() -> String {
+    let jst = chrono::FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    let now = chrono::Utc::now().with_timezone(&jst);
+    let days = if chrono::Timelike::hour(&now) < 12 { 2 } else { 1 };
+    (now - chrono::Duration::days(days))
+        .format("%Y-%m-%d")
+        .to_string()
+}
+
+pub(crate) async fn get_ranking_capture_modes_() -> Vec<RankMode> {
+    let value = load_property(RANKING_CAPTURE_MODES_KEY.to_owned())
+        .await
+        .unwrap_or_default();
+    serde_json::from_str(&value).unwrap_or_default()
+}
+
+pub(crate) async fn set_ranking_capture_modes_(modes: Vec<RankMode>) -> Result<()> {
+    let mut distinct: Vec<RankMode> = vec![];
+    for mode in modes {
+        if !distinct.contains(&mode) {
+            distinct.push(mode);
+        }
+    }
+    save_property(
+        RANKING_CAPTURE_MODES_KEY.to_owned(),
+        serde_json::to_string(&distinct)?,
+    )
+    .await?;
+    Ok(())
+}
+
+/// 校验排行榜日期, 返回 yyyy-MM-dd 格式(例如 2024-1-5 变为 2024-01-05)
+fn canonical_ranking_date(date: &str) -> Result<String> {
+    let parsed = chrono::NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+        .map_err(|_| anyhow::Error::msg(format!("invalid ranking date: {}", date)))?;
+    Ok(parsed.format("%Y-%m-%d").to_string())
+}
+
+/// 读取某一天的完整排行榜并保存快照(不使用本地屏蔽规则), 返回记录的作品数量
+///
+/// 直接请求网络, 不使用响应缓存, 以免记录到缓存中的旧数据
+pub(crate) async fn capture_ranking_(mode: RankMode, date: String) -> Result<i64> {
+    let date = canonical_ranking_date(&date)?;
+    let mut url = Some(client(-1).await?.illust_rank_first_url(mode, date.clone()));
+    let mut seen = HashSet::new();
+    let mut entries = vec![];
+    let mut pages = 0;
+    while let Some(current) = url {
+        if pages >= RANKING_CAPTURE_MAX_PAGES {
+            break;
+        }
+        pages += 1;
+        let text = with_auth_retry(2, |client| {
+            let current = current.clone();
+            async move { client.get_from_pixiv_raw(current).await }
+        })
+        .await?;
+        let page: IllustResponse = serde_json::from_str(&text)?;
+        for illust in page.illusts {
+            if seen.insert(illust.id) {
+                entries.push((illust.id, illust.user.id, serde_json::to_string(&illust)?));
+            }
+        }
+        url = page.next_url;
+    }
+    if entries.is_empty() {
+        return Err(anyhow::Error::msg(format!(
+            "ranking {} {} is empty",
+            mode.as_str(),
+            date
+        )));
+    }
+    let count = entries.len() as i64;
+    ranking_snapshot::replace(mode.as_str(), &date, entries).await?;
+    Ok(count)
+}
+
+/// 记录所选排行榜的最新一期, 已经记录过的跳过
+pub(crate) async fn capture_pending_rankings() -> Result<()> {
+    let date = latest_ranking_date();
+    for mode in get_ranking_capture_modes_().await {
+        if ranking_snapshot::exists(mode.as_str(), &date).await? {
+            continue;
+        }
+        match capture_ranking_(mode, date.clone()).await {
+            std::result::Result::Ok(count) => {
+                println!("Captured ranking {} {} : {}", mode.as_str(), date, count)
+            }
+            Err(err) => println!("Failed to capture ranking {} {} : {}", mode.as_str(), date, err),
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn start_ranking_capture_loop() {
+    tokio::spawn(async {
+        loop {
+            // 未登录时不能读取排行榜
+            if TOKEN.lock().await.created_time != 0 {
+                if let Err(err) = capture_pending_rankings().await {
+                    println!("Failed to capture rankings : {}", err);
+                }
+            }
+            tokio::time::sleep(std::time::Duration::from_secs(RANKING_CAPTURE_INTERVAL_SECS))
+                .await;
+        }
+    });
+}
+
+fn ranking_entry_dto(mode: RankMode, model: ranking_snapshot::Model) -> Result<RankingEntryDto> {
+    Ok(RankingEntryDto {
+        mode,
+        date: model.date,
+        rank: model.rank,
+        illust: serde_json::from_str(&model.illust_json)?,
+        captured_time: model.captured_time,
+    })
+}
+
+pub(crate) async fn ranking_snapshot_(mode: RankMode, date: String) -> Result<Vec<RankingEntryDto>> {
+    ranking_snapshot::find(mode.as_str(), &canonical_ranking_date(&date)?)
+        .await?
+        .into_iter()
+        .map(|model| ranking_entry_dto(mode, model))
+        .collect()
+}
+
+pub(crate) async fn ranking_snapshot_dates_(mode: RankMode) -> Result<Vec<String>> {
+    Ok(ranking_snapshot::dates(mode.as_str()).await?)
+}
+
+/// 某个作者在排行榜中的历史名次, 新的在前
+pub(crate) async fn ranking_history_of_user_(
+    mode: RankMode,
+    user_id: i64,
+) -> Result<Vec<RankingEntryDto>> {
+    ranking_snapshot::find_by_user(mode.as_str(), user_id)
+        .await?
+        .into_iter()
+        .map(|model| ranking_entry_dto(mode, model))
+        .collect()
+}
+
+/// 比较两天的排行榜, 作品信息使用较新的一天的快照
+pub(crate) async fn ranking_diff_(
+    mode: RankMode,
+    from_date: String,
+    to_date: String,
+) -> Result<RankingDiff> {
+    let from_date = canonical_ranking_date(&from_date)?;
+    let to_date = canonical_ranking_date(&to_date)?;
+    let from = ranking_snapshot::find(mode.as_str(), &from_date).await?;
+    let to = ranking_snapshot::find(mode.as_str(), &to_date).await?;
+    if from.is_empty() || to.is_empty() {
+        return Err(anyhow::Error::msg("ranking snapshot not found"));
+    }
+    diff_rankings(mode, from_date, to_date, from, to)
+}
+
+/// 比较两天的排行榜快照, from / to 按名次排序
+fn diff_rankings(
+    mode: RankMode,
+    from_date: String,
+    to_date: String,
+    from: Vec<ranking_snapshot::Model>,
+    to: Vec<ranking_snapshot::Model>,
+) -> Result<RankingDiff> {
+    let from_ranks: HashMap<i64, i64> = from.iter().map(|m| (m.illust_id, m.rank)).collect();
+    let to_ids: HashSet<i64> = to.iter().map(|m| m.illust_id).collect();
+    let mut diff = RankingDiff {
+        mode,
+        from_date,
+        to_date,
+        new_entries: vec![],
+        climbed: vec![],
+        fell: vec![],
+        dropped: vec![],
+    };
+    for model in to {
+        let from_rank = from_ranks.get(&model.illust_id).cloned();
+        let item = RankingMoveDto {
+            illust: serde_json::from_str(&model.illust_json)?,
+            from_rank,
+            to_rank: Some(model.rank),
+        };
+        match from_rank {
+            None => diff.new_entries.push(item),
+            Some(from_rank) if model.rank < from_rank => diff.climbed.push(item),
+            Some(from_rank) if model.rank > from_rank => diff.fell.push(item),
+            Some(_) => {}
+        }
+    }
+    for model in from {
+        if !to_ids.contains(&model.illust_id) {
+            diff.dropped.push(RankingMoveDto {
+                illust: serde_json::from_str(&model.illust_json)?,
+                from_rank: Some(model.rank),
+                to_rank: None,
+            });
+        }
+    }
+    let change = |m: &RankingMoveDto| m.from_rank.unwrap_or_default() - m.to_rank.unwrap_or_default();
+    diff.climbed.sort_by_key(|m| -change(m));
+    diff.fell.sort_by_key(|m| change(m));
+    Ok(diff)
+}
+
+pub(crate) async fn clear_ranking_snapshots_() -> Result<()> {
+    ranking_snapshot::delete_all().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixirust::backend::{BoxFuture, HttpBackend, HttpResponse};
+    use crate::{block_on, test_env};
+    use std::sync::atomic::{AtomicBool, AtomicUsize};
+
+    const APP: &str = "http://app.test";
+    const OAUTH: &str = "http://oauth.test";
+
+    /// 模拟 OAuth 服务和 app-api: 刷新时签发新的 access token, app-api 只接受最新的 token
+    #[derive(Default)]
+    struct MockState {
+        refreshes: AtomicUsize,
+        app_requests: AtomicUsize,
+        valid_token: std::sync::Mutex<String>,
+        /// 刷新返回的错误 (status, error)
+        refresh_error: std::sync::Mutex<Option<(u16, String)>>,
+        /// app-api 总是认为 token 无效
+        reject_all: AtomicBool,
+    }
+
+    struct MockOAuth(Arc<MockState>);
+
+    impl MockOAuth {
+        fn handle(&self, request: HttpRequest) -> HttpResponse {
+            let state = &self.0;
+            if request.url.starts_with(OAUTH) {
+                let n = state.refreshes.fetch_add(1, Ordering::SeqCst) + 1;
+                if let Some((status, error)) = state.refresh_error.lock().unwrap().clone() {
+                    return response(
+                        status,
+                        serde_json::json!({
+                            "has_error": true,
+                            "errors": {"system": {"message": error.clone(), "code": 1508}},
+                            "error": error,
+                        }),
+                    );
+                }
+                let access_token = format!("access-{n}");
+                *state.valid_token.lock().unwrap() = access_token.clone();
+                return response(200, serde_json::to_value(token(&access_token)).unwrap());
+            }
+            state.app_requests.fetch_add(1, Ordering::SeqCst);
+            let authorization = format!("Bearer {}", state.valid_token.lock().unwrap());
+            let authed = request
+                .headers
+                .iter()
+                .any(|(k, v)| k == "Authorization" && *v == authorization);
+            if authed && !state.reject_all.load(Ordering::SeqCst) {
+                return response(200, serde_json::json!({}));
+            }
+            response(
+                400,
+                serde_json::json!({"error": {
+                    "user_message": "",
+                    "message": "Error occurred at the OAuth process. Please check your Access Token to fix this. Error Message: invalid_token",
+                    "reason": "",
+                    "user_message_details": {},
+                }}),
+            )
+        }
+    }
+
+    impl HttpBackend for MockOAuth {
+        fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+            Box::pin(async move {
+                // 让并发的请求有机会同时等待刷新
+                tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                Ok(self.handle(request))
+            })
+        }
+    }
+
+    fn response(status: u16, body: serde_json::Value) -> HttpResponse {
+        HttpResponse {
+            status,
+            headers: vec![],
+            body: body.to_string().into(),
+        }
+    }
+
+    fn token(access_token: &str) -> Token {
+        Token {
+            access_token: access_token.to_owned(),
+            refresh_token: "refresh".to_owned(),
+            expires_in: 3600,
+            user: crate::pixirust::entities::TokenUser {
+                id: "1".to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// 安装模拟服务并登录, age_secs 为token已使用的时间
+    async fn login(age_secs: i64) -> Arc<MockState> {
+        let state = Arc::new(MockState::default());
+        *state.valid_token.lock().unwrap() = "access-0".to_owned();
+        let mut client = Client::with_backend(Box::new(MockOAuth(state.clone())));
+        client.set_servers(ServerUrls {
+            app: APP.to_owned(),
+            oauth: OAUTH.to_owned(),
+            image: APP.to_owned(),
+        });
+        *CLIENT.write().await = Arc::new(client);
+        let created_time = chrono::Local::now().timestamp_millis() - age_secs * 1000;
+        set_token(token("access-0"), created_time).await.unwrap();
+        state
+    }
+
+    async fn get(auth_level: i32) -> Result<String> {
+        with_auth_retry(auth_level, |client| async move {
+            client.get_from_pixiv_raw(format!("{APP}/v1/test")).await
+        })
+        .await
+    }
+
+    #[test]
+    fn refreshes_expired_token_before_request() {
+        let _env = test_env();
+        block_on(async {
+            let state = login(2 * 3600).await;
+            get(1).await.unwrap();
+            assert_eq!(state.refreshes.load(Ordering::SeqCst), 1);
+            assert_eq!(state.app_requests.load(Ordering::SeqCst), 1);
+            assert_eq!(TOKEN.lock().await.token.access_token, "access-1");
+            let accounts = load_accounts().await.unwrap();
+            assert_eq!(accounts.find("1").unwrap().token.access_token, "access-1");
+        });
+    }
+
+    #[test]
+    fn concurrent_requests_refresh_once() {
+        let _env = test_env();
+        block_on(async {
+            let state = login(2 * 3600).await;
+            let results = tokio::join!(get(1), get(1), get(1), get(1));
+            for result in [results.0, results.1, results.2, results.3] {
+                result.unwrap();
+            }
+            assert_eq!(state.refreshes.load(Ordering::SeqCst), 1);
+            assert_eq!(state.app_requests.load(Ordering::SeqCst), 4);
+        });
+    }
+
+    #[test]
+    fn retries_after_invalid_token() {
+        let _env = test_env();
+        block_on(async {
+            let state = login(0).await;
+            // 服务端提前吊销了 token
+            *state.valid_token.lock().unwrap() = "revoked".to_owned();
+            get(1).await.unwrap();
+            assert_eq!(state.refreshes.load(Ordering::SeqCst), 1);
+            assert_eq!(state.app_requests.load(Ordering::SeqCst), 2);
+        });
+    }
+
+    #[test]
+    fn gives_up_after_one_retry() {
+        let _env = test_env();
+        block_on(async {
+            let state = login(0).await;
+            state.reject_all.store(true, Ordering::SeqCst);
+            let err = get(1).await.unwrap_err();
+            assert!(err.downcast_ref::<AuthError>().is_some());
+            assert_eq!(state.refreshes.load(Ordering::SeqCst), 1);
+            assert_eq!(state.app_requests.load(Ordering::SeqCst), 2);
+        });
+    }
+
+    #[test]
+    fn server_error_keeps_login() {
+        let _env = test_env();
+        block_on(async {
+            let state = login(2 * 3600).await;
+            *state.refresh_error.lock().unwrap() = Some((503, "server_error".to_owned()));
+            let err = get(1).await.unwrap_err();
+            assert!(!err.downcast_ref::<OAuthError>().unwrap().is_rejected());
+            assert_eq!(TOKEN.lock().await.token.access_token, "access-0");
+            // 服务恢复后下次请求重新刷新
+            *state.refresh_error.lock().unwrap() = None;
+            get(1).await.unwrap();
+            assert_eq!(state.refreshes.load(Ordering::SeqCst), 2);
+        });
+    }
+
+    #[test]
+    fn invalid_grant_logs_out() {
+        let _env = test_env();
+        block_on(async {
+            let state = login(2 * 3600).await;
+            *state.refresh_error.lock().unwrap() = Some((400, "invalid_grant".to_owned()));
+            let err = get(1).await.unwrap_err();
+            assert!(err.downcast_ref::<OAuthError>().is_some());
+            assert_eq!(state.app_requests.load(Ordering::SeqCst), 0);
+            assert_eq!(TOKEN.lock().await.created_time, 0);
+            assert!(load_accounts().await.unwrap().find("1").is_none());
+            // 未登录后不再尝试刷新
+            get(0).await.unwrap_err();
+            assert_eq!(state.refreshes.load(Ordering::SeqCst), 1);
+        });
+    }
+
+    #[test]
+    fn refreshes_five_minutes_before_expiry() {
+        let now = chrono::Local::now().timestamp_millis();
+        let period = |created_time| TokenPeriod {
+            token: Token {
+                expires_in: 3600,
+                ..Default::default()
+            },
+            created_time,
+        };
+        assert!(!expires_soon(&period(now), now));
+        assert!(!expires_soon(&period(now - 54 * 60 * 1000), now));
+        assert!(expires_soon(&period(now - 56 * 60 * 1000), now));
+        assert!(expires_soon(&period(now - 2 * 3600 * 1000), now));
+    }
+
+    #[tokio::test]
+    async fn cancel_drops_running_calls() {
+        let running = tokio::spawn(cancellable("cancel-test", std::future::pending::<Result<()>>()));
+        while !CANCEL_TOKENS.lock().unwrap().contains_key("cancel-test") {
+            tokio::task::yield_now().await;
+        }
+        assert!(cancel_("cancel-test"));
+        let err = running.await.unwrap().unwrap_err();
+        assert!(err.downcast_ref::<Cancelled>().is_some());
+        assert!(!cancel_("cancel-test"));
+        // 取消之后同一个id的新请求不受影响, 结束后不再登记
+        assert_eq!(cancellable("cancel-test", async { Ok(1) }).await.unwrap(), 1);
+        assert!(!CANCEL_TOKENS.lock().unwrap().contains_key("cancel-test"));
+    }
+
+    fn illust_page(ids: &[i64], next_url: Option<&str>) -> IllustResponse {
+        IllustResponse {
+            illusts: ids
+                .iter()
+                .map(|id| Illust {
+                    id: *id,
+                    ..Default::default()
+                })
+                .collect(),
+            next_url: next_url.map(str::to_owned),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn cursor_skips_seen_illusts() {
+        let mut cursor = FeedCursor::new("first".to_owned());
+        let first = cursor.accept(illust_page(&[1, 2, 3], Some("second")));
+        assert_eq!(first.illusts.len(), 3);
+        assert_eq!(cursor.next_url.as_deref(), Some("second"));
+        // 翻页期间排名变化, 已经返回过的作品不再返回
+        let second = cursor.accept(illust_page(&[3, 4], None));
+        assert_eq!(second.illusts.iter().map(|i| i.id).collect::<Vec<_>>(), vec![4]);
+        assert_eq!(cursor.next_url, None);
+    }
+
+    #[tokio::test]
+    async fn closed_cursor_is_not_found() {
+        let handle = cursor_open_("first".to_owned());
+        assert!(cursor_collect_all_(handle, 0).await.is_err());
+        cursor_close_(handle);
+        assert!(cursor_next_(handle).await.is_err());
+    }
+
+    fn ranking_models(date: &str, ids: &[i64]) -> Vec<ranking_snapshot::Model> {
+        ids.iter()
+            .enumerate()
+            .map(|(idx, id)| ranking_snapshot::Model {
+                mode: RankMode::Week.as_str().to_owned(),
+                date: date.to_owned(),
+                rank: idx as i64 + 1,
+                illust_id: *id,
+                user_id: 0,
+                illust_json: serde_json::to_string(&Illust {
+                    id: *id,
+                    ..Default::default()
+                })
+                .unwrap(),
+                captured_time: 0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn ranking_diff_classifies_and_sorts() {
+        let diff = diff_rankings(
+            RankMode::Week,
+            "2001-01-01".to_owned(),
+            "2001-01-02".to_owned(),
+            ranking_models("2001-01-01", &[1, 2, 3, 4, 5, 6]),
+            ranking_models("2001-01-02", &[7, 6, 1, 5, 2, 8, 3]),
+        )
+        .unwrap();
+        let moves = |items: &[RankingMoveDto]| -> Vec<(i64, Option<i64>, Option<i64>)> {
+            items
+                .iter()
+                .map(|m| (m.illust.id, m.from_rank, m.to_rank))
+                .collect()
+        };
+        assert_eq!(moves(&diff.new_entries), vec![(7, None, Some(1)), (8, None, Some(6))]);
+        assert_eq!(
+            moves(&diff.climbed),
+            vec![(6, Some(6), Some(2)), (5, Some(5), Some(4))]
+        );
+        assert_eq!(
+            moves(&diff.fell),
+            vec![(3, Some(3), Some(7)), (2, Some(2), Some(5)), (1, Some(1), Some(3))]
+        );
+        assert_eq!(moves(&diff.dropped), vec![(4, Some(4), None)]);
+    }
+
+    #[test]
+    fn unreadable_accounts_are_moved_aside() {
+        let _env = test_env();
+        block_on(async {
+            let blob = format!("enc:v1:{}", "A".repeat(64));
+            save_property("accounts.json".to_owned(), blob.clone())
+                .await
+                .unwrap();
+            PENDING_AUTH_EVENTS.lock().unwrap().clear();
+            assert!(load_accounts().await.unwrap().accounts.is_empty());
+            assert!(load_property("accounts.json".to_owned())
+                .await
+                .unwrap()
+                .is_empty());
+            // 没有监听时事件保留到开始监听
+            let event = PENDING_AUTH_EVENTS.lock().unwrap().pop().unwrap();
+            assert_eq!(event.kind, AuthEventKind::AccountsUnreadable);
+            let key = event.reason.trim_end_matches(')').rsplit('(').next().unwrap();
+            assert_eq!(load_property(key.to_owned()).await.unwrap(), blob);
+        });
+    }
+}
+
+#[cfg(test)]
+mod cursor_tests {
+    use super::*;
+    use crate::fake_server_tests::{login, start_server};
+    use crate::{block_on, test_env};
+
+    /// fake_server 的推荐共 3 页, 每页 30 个, id 从 1 开始
+    async fn open_recommended(faults: fake_server::Faults) -> i64 {
+        start_server(faults).await;
+        assert!(login("code").await.unwrap());
+        let url = CLIENT.read().await.illust_recommended_first_url();
+        cursor_open_(url)
+    }
+
+    fn ids(illusts: &[Illust]) -> Vec<i64> {
+        illusts.iter().map(|i| i.id).collect()
+    }
+
+    #[test]
+    fn collect_all_keeps_overflow() {
+        let _env = test_env();
+        block_on(async {
+            let handle = open_recommended(Default::default()).await;
+            let collected = cursor_collect_all_(handle, 40).await.unwrap();
+            assert_eq!(ids(&collected), (1..=40).collect::<Vec<_>>());
+            // 第2页剩下的 41..=60 由下一次读取返回
+            let page = cursor_next_(handle).await.unwrap().unwrap();
+            assert_eq!(ids(&page.illusts), (41..=60).collect::<Vec<_>>());
+            let rest = cursor_collect_all_(handle, 100).await.unwrap();
+            assert_eq!(ids(&rest), (61..=90).collect::<Vec<_>>());
+            assert!(cursor_next_(handle).await.unwrap().is_none());
+            cursor_close_(handle);
+        });
+    }
+
+    #[test]
+    fn stream_stops_after_max_pages() {
+        let _env = test_env();
+        block_on(async {
+            let handle = open_recommended(Default::default()).await;
+            let mut pages = vec![];
+            cursor_send_pages(handle, 2, |page| {
+                pages.push(ids(&page.illusts));
+                true
+            })
+            .await
+            .unwrap();
+            assert_eq!(pages.len(), 2);
+            // 游标停在第3页
+            let page = cursor_next_(handle).await.unwrap().unwrap();
+            assert_eq!(page.illusts.first().map(|i| i.id), Some(61));
+            assert!(cursor_send_pages(handle, 0, |_| true).await.is_err());
+            cursor_close_(handle);
+        });
+    }
+
+    #[test]
+    fn stream_releases_cursor_between_pages() {
+        let _env = test_env();
+        block_on(async {
+            // 每页有延迟, 读取下一页时 cursor_next 已经在等待
+            let handle = open_recommended(fake_server::Faults {
+                latency_ms: 100,
+                ..Default::default()
+            })
+            .await;
+            let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+            let stream = tokio::spawn(cursor_send_pages(handle, 10, move |page| {
+                tx.send(page).is_ok()
+            }));
+            // 收到第1页后 cursor_next 可以读取, 不需要等待 stream 结束
+            let first = rx.recv().await.unwrap();
+            let next = cursor_next_(handle).await.unwrap();
+            stream.await.unwrap().unwrap();
+            let mut seen: Vec<i64> = ids(&first.illusts);
+            seen.extend(ids(&next.unwrap().illusts));
+            while let Some(page) = rx.recv().await {
+                seen.extend(ids(&page.illusts));
+            }
+            seen.sort();
+            assert_eq!(seen, (1..=90).collect::<Vec<_>>());
+            cursor_close_(handle);
+        });
+    }
+}
+
+#[cfg(test)]
+mod ranking_tests {
+    use super::*;
+    use crate::fake_server_tests::{login, server_requests, start_server};
+    use crate::{block_on, test_env};
+
+    async fn save(mode: RankMode, date: &str, ids: &[i64]) {
+        let entries = ids
+            .iter()
+            .map(|id| {
+                let illust = Illust {
+                    id: *id,
+                    ..Default::default()
+                };
+                (*id, 0, serde_json::to_string(&illust).unwrap())
+            })
+            .collect();
+        ranking_snapshot::replace(mode.as_str(), date, entries)
+            .await
+            .unwrap();
+    }
+
+    fn moves(items: &[RankingMoveDto]) -> Vec<(i64, Option<i64>, Option<i64>)> {
+        items
+            .iter()
+            .map(|m| (m.illust.id, m.from_rank, m.to_rank))
+            .collect()
+    }
+
+    #[test]
+    fn diff_reads_snapshots_by_canonical_date() {
+        let _env = test_env();
+        block_on(async {
+            save(RankMode::Week, "2001-01-01", &[1, 2]).await;
+            save(RankMode::Week, "2001-01-02", &[2, 3]).await;
+            let diff = ranking_diff_(RankMode::Week, "2001-1-1".to_owned(), "2001-1-2".to_owned())
+                .await
+                .unwrap();
+            assert_eq!(diff.from_date, "2001-01-01");
+            assert_eq!(diff.to_date, "2001-01-02");
+            assert_eq!(moves(&diff.new_entries), vec![(3, None, Some(2))]);
+            assert_eq!(moves(&diff.climbed), vec![(2, Some(2), Some(1))]);
+            assert_eq!(moves(&diff.dropped), vec![(1, Some(1), None)]);
+        });
+    }
+
+    #[test]
+    fn rejects_invalid_dates() {
+        let _env = test_env();
+        block_on(async {
+            assert!(capture_ranking_(RankMode::Day, "2024/01/05".to_owned()).await.is_err());
+            assert!(ranking_snapshot_(RankMode::Day, "yesterday".to_owned()).await.is_err());
+            assert!(
+                ranking_diff_(RankMode::Day, "2024-02-30".to_owned(), "2024-03-01".to_owned())
+                    .await
+                    .is_err()
+            );
+        });
+    }
+
+    #[test]
+    fn capture_skips_response_cache() {
+        let _env = test_env();
+        block_on(async {
+            let base = start_server(Default::default()).await;
+            assert!(login("code").await.unwrap());
+            let url = client(-1)
+                .await
+                .unwrap()
+                .illust_rank_first_url(RankMode::Day, "2024-01-05".to_owned());
+            let _: (IllustResponse, bool) = cached_get(2, url).await.unwrap();
+
+            // fake_server 的排行榜共 3 页, 每页 30 个
+            assert_eq!(capture_ranking_(RankMode::Day, "2024-1-5".to_owned()).await.unwrap(), 90);
+            let requests = server_requests(&base).await;
+            let ranking = requests
+                .iter()
+                .filter(|r| r.starts_with("GET /v1/illust/ranking"))
+                .count();
+            assert_eq!(ranking, 4);
+            let snapshot = ranking_snapshot_(RankMode::Day, "2024-01-05".to_owned())
+                .await
+                .unwrap();
+            assert_eq!(snapshot.len(), 90);
+            assert!(snapshot.iter().all(|e| e.date == "2024-01-05"));
+        });
+    }
 }
@@ -0,0 +1,656 @@
+use super::bypass_tls::{bypass_tls_config, hosts_by_ip};
+use super::doh::DohResolver;
+use crate::udto::ConnectionProfile;
+use anyhow::{Error, Result};
+use bytes::Bytes;
+use reqwest::Method;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// 发给 HttpBackend 的请求
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub form: Option<Vec<(String, String)>>,
+}
+
+impl HttpRequest {
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            headers: vec![],
+            form: None,
+        }
+    }
+
+    pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn form(mut self, form: Vec<(&str, String)>) -> Self {
+        self.form = Some(form.into_iter().map(|(k, v)| (k.to_owned(), v)).collect());
+        self
+    }
+}
+
+/// HttpBackend 返回的响应, body 已完整读取
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Bytes,
+}
+
+impl HttpResponse {
+    /// 按名称(不区分大小写)取响应头
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn text(&self) -> Result<String> {
+        Ok(String::from_utf8(self.body.to_vec())?)
+    }
+}
+
+/// Client 的网络传输层, 可以替换为回放录制数据的实现以便离线测试
+pub trait HttpBackend: Send + Sync {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>>;
+}
+
+/// 构建 ReqwestBackend 的网络设置
+#[derive(Default)]
+pub struct BackendOptions {
+    pub proxy: Option<reqwest::Proxy>,
+    /// DoH 只用于普通请求, SNI bypass 直接连接IP
+    pub resolver: Option<DohResolver>,
+    /// SNI bypass 的 host -> ip(可以有多个)
+    pub bypass_hosts: HashMap<String, String>,
+    pub profile: ConnectionProfile,
+}
+
+/// 基于 reqwest 的实现, 按设置决定是否使用 SNI bypass
+pub struct ReqwestBackend {
+    agent: reqwest::Client,
+    /// SNI bypass 时每个IP一个客户端, 对应的域名都解析到该IP, 其余与普通请求相同
+    agents_sni_bypass: HashMap<String, reqwest::Client>,
+}
+
+/// 超时 / 连接池 / keepalive / 压缩, 两种 agent 共用
+fn apply_profile(
+    mut builder: reqwest::ClientBuilder,
+    profile: &ConnectionProfile,
+) -> reqwest::ClientBuilder {
+    builder = builder
+        .connect_timeout(Duration::from_secs(profile.connect_timeout_secs))
+        .read_timeout(Duration::from_secs(profile.read_timeout_secs))
+        .pool_max_idle_per_host(profile.pool_max_idle_per_host)
+        .pool_idle_timeout(Duration::from_secs(profile.pool_idle_timeout_secs))
+        .gzip(profile.compression)
+        .brotli(profile.compression);
+    // 0 表示不限制
+    if profile.total_timeout_secs > 0 {
+        builder = builder.timeout(Duration::from_secs(profile.total_timeout_secs));
+    }
+    if profile.tcp_keepalive_secs > 0 {
+        builder = builder.tcp_keepalive(Duration::from_secs(profile.tcp_keepalive_secs));
+    }
+    if !profile.http2 {
+        builder = builder.http1_only();
+    }
+    builder
+}
+
+impl ReqwestBackend {
+    pub fn new() -> Self {
+        Self::with_options(BackendOptions::default()).unwrap()
+    }
+
+    pub fn with_options(options: BackendOptions) -> Result<Self> {
+        let profile = &options.profile;
+        let mut agent = apply_profile(reqwest::ClientBuilder::new(), profile);
+        if let Some(proxy) = options.proxy.clone() {
+            agent = agent.proxy(proxy);
+        }
+        if let Some(resolver) = options.resolver {
+            agent = agent.dns_resolver(Arc::new(resolver));
+        }
+        // url 保持真实域名, HTTP/2 的 :authority 和证书校验都使用该域名
+        let tls = bypass_tls_config(profile.http2)?;
+        let mut agents_sni_bypass = HashMap::new();
+        for (ip, hosts) in hosts_by_ip(&options.bypass_hosts) {
+            let mut builder =
+                apply_profile(reqwest::ClientBuilder::new(), profile).use_preconfigured_tls(tls.clone());
+            for host in &hosts {
+                builder = builder.resolve(host, SocketAddr::new(ip, 0));
+            }
+            if let Some(proxy) = options.proxy.clone() {
+                builder = builder.proxy(proxy);
+            }
+            agents_sni_bypass.insert(ip.to_string(), builder.build()?);
+        }
+        Ok(Self {
+            agent: agent.build()?,
+            agents_sni_bypass,
+        })
+    }
+
+    /// 返回依次尝试的请求, SNI bypass 时每个候选IP一个(按健康状况排序), 否则只有一个
+    async fn build_request(
+        &self,
+        method: Method,
+        url: &str,
+    ) -> Vec<(Option<String>, reqwest::RequestBuilder)> {
+        let bypass = crate::local::get_bypass_sni_().await;
+        if !bypass {
+            return vec![(None, self.agent.request(method, url))];
+        }
+
+        let parsed = reqwest::Url::parse(url);
+        if let Ok(parsed) = parsed {
+            if parsed.scheme() != "https" {
+                return vec![(None, self.agent.request(method, parsed))];
+            }
+            let host = parsed.host_str().map(|h| h.to_string());
+            if let Some(host) = host {
+                let mut requests = vec![];
+                for ip in crate::local::get_bypass_sni_ips_for_host(&host).await {
+                    if let Some(agent) = self.agents_sni_bypass.get(&ip) {
+                        requests.push((Some(ip), agent.request(method.clone(), parsed.clone())));
+                    }
+                }
+                if !requests.is_empty() {
+                    return requests;
+                }
+            }
+            return vec![(None, self.agent.request(method, parsed))];
+        }
+        vec![(None, self.agent.request(method, url))]
+    }
+
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let mut last_error = Error::msg("no request");
+        for (ip, mut req) in self.build_request(request.method.clone(), &request.url).await {
+            for (k, v) in &request.headers {
+                req = req.header(k.as_str(), v.as_str());
+            }
+            if let Some(form) = &request.form {
+                req = req.form(form);
+            }
+            let rsp = match req.send().await {
+                Ok(rsp) => rsp,
+                Err(err) => {
+                    // 连接失败时换下一个IP; 超时的请求可能已经到达服务端, 只有GET/HEAD可以重发
+                    if let Some(ip) = ip {
+                        let idempotent = request.method == Method::GET || request.method == Method::HEAD;
+                        if err.is_connect() || (err.is_timeout() && idempotent) {
+                            crate::local::report_bypass_sni_failure(&ip, &err.to_string()).await;
+                            last_error = err.into();
+                            continue;
+                        }
+                    }
+                    return Err(err.into());
+                }
+            };
+            let status = rsp.status().as_u16();
+            let headers = rsp
+                .headers()
+                .iter()
+                .filter_map(|(k, v)| Some((k.to_string(), v.to_str().ok()?.to_owned())))
+                .collect();
+            let body = rsp.bytes().await?;
+            return Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            });
+        }
+        Err(last_error)
+    }
+}
+
+impl HttpBackend for ReqwestBackend {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        Box::pin(self.execute(request))
+    }
+}
+
+/// 录制的一条响应, 按请求方法和url的路径(含查询参数前缀)匹配, 不区分host
+///
+/// body 可以是原始文本, 也可以直接写成 JSON, 读取时序列化回文本
+#[cfg(test)]
+#[derive(Debug, Clone, serde_derive::Deserialize)]
+pub struct Fixture {
+    pub method: String,
+    pub path: String,
+    pub status: u16,
+    #[serde(deserialize_with = "fixture_body")]
+    pub body: String,
+}
+
+#[cfg(test)]
+fn fixture_body<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    Ok(match <serde_json::Value as serde::Deserialize>::deserialize(deserializer)? {
+        serde_json::Value::String(text) => text,
+        value => value.to_string(),
+    })
+}
+
+/// 回放录制响应的 HttpBackend, 不访问网络, 同时记录收到的请求
+///
+/// clone 出的实例共享响应和请求记录, 交给 Client 后仍可以检查发出的请求
+#[cfg(test)]
+#[derive(Clone, Default)]
+pub struct FixtureBackend {
+    fixtures: Arc<std::sync::Mutex<Vec<Fixture>>>,
+    requests: Arc<std::sync::Mutex<Vec<HttpRequest>>>,
+}
+
+#[cfg(test)]
+impl FixtureBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 读取目录下所有 .json 文件, 每个文件为一个 Fixture
+    pub fn from_dir(dir: &str) -> Result<Self> {
+        let backend = Self::new();
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|e| e == "json") {
+                let fixture: Fixture = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+                backend.fixtures.lock().unwrap().push(fixture);
+            }
+        }
+        Ok(backend)
+    }
+
+    pub fn add(&self, method: Method, path: &str, status: u16, body: &str) {
+        self.fixtures.lock().unwrap().push(Fixture {
+            method: method.to_string(),
+            path: path.to_owned(),
+            status,
+            body: body.to_owned(),
+        });
+    }
+
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn replay(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let url = reqwest::Url::parse(&request.url)?;
+        let path = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_owned(),
+        };
+        self.requests.lock().unwrap().push(request.clone());
+        let fixtures = self.fixtures.lock().unwrap();
+        // 匹配最长的路径, 长度相同时后添加的优先, 便于覆盖目录中的默认响应
+        let fixture = fixtures
+            .iter()
+            .filter(|f| f.method.eq_ignore_ascii_case(request.method.as_str()))
+            .filter(|f| path.starts_with(&f.path))
+            .max_by_key(|f| f.path.len())
+            .ok_or_else(|| Error::msg(format!("no fixture for {} {}", request.method, path)))?;
+        Ok(HttpResponse {
+            status: fixture.status,
+            headers: vec![],
+            body: Bytes::from(fixture.body.clone()),
+        })
+    }
+}
+
+#[cfg(test)]
+impl HttpBackend for FixtureBackend {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        Box::pin(async move { self.replay(request) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixirust::client::{AuthError, Client, OAuthError, ServerUrls};
+    use crate::pixirust::entities::{
+        IllustResponse, RankMode, Restrict, Token, TokenUser, UserDetail, UserPreview,
+        UserPreviewsResponse,
+    };
+
+    fn client(backend: &FixtureBackend) -> Client {
+        let mut client = Client::with_backend(Box::new(backend.clone()));
+        client.set_servers(ServerUrls {
+            app: "http://app.test".to_owned(),
+            oauth: "http://oauth.test".to_owned(),
+            image: "http://image.test".to_owned(),
+        });
+        client
+    }
+
+    fn form(request: &HttpRequest) -> HashMap<String, String> {
+        request.form.clone().unwrap_or_default().into_iter().collect()
+    }
+
+    fn token_json(access_token: &str) -> String {
+        serde_json::to_string(&Token {
+            access_token: access_token.to_owned(),
+            refresh_token: "refresh".to_owned(),
+            expires_in: 3600,
+            user: TokenUser {
+                id: "1".to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    fn app_error_json(message: &str) -> String {
+        serde_json::json!({"error": {
+            "user_message": "",
+            "message": message,
+            "reason": "",
+            "user_message_details": {},
+        }})
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn login_and_refresh() {
+        let backend = FixtureBackend::new();
+        backend.add(Method::POST, "/auth/token", 200, &token_json("access"));
+        let client = client(&backend);
+
+        let token = client
+            .load_token_by_code("code".to_owned(), "verify".to_owned())
+            .await
+            .unwrap();
+        assert_eq!(token.access_token, "access");
+        let token = client.refresh_token("refresh").await.unwrap();
+        assert_eq!(token.user.id, "1");
+
+        let requests = backend.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].url, "http://oauth.test/auth/token");
+        let login = form(&requests[0]);
+        assert_eq!(login["grant_type"], "authorization_code");
+        assert_eq!(login["code"], "code");
+        assert_eq!(login["code_verifier"], "verify");
+        let refresh = form(&requests[1]);
+        assert_eq!(refresh["grant_type"], "refresh_token");
+        assert_eq!(refresh["refresh_token"], "refresh");
+    }
+
+    #[tokio::test]
+    async fn feeds_follow_next_url() {
+        let backend = FixtureBackend::new();
+        let first = IllustResponse {
+            illusts: vec![Default::default()],
+            next_url: Some("http://app.test/v1/illust/recommended?offset=30".to_owned()),
+            ..Default::default()
+        };
+        backend.add(
+            Method::GET,
+            "/v1/illust/recommended",
+            200,
+            &serde_json::to_string(&first).unwrap(),
+        );
+        backend.add(
+            Method::GET,
+            "/v1/illust/recommended?offset=30",
+            200,
+            &serde_json::to_string(&IllustResponse::default()).unwrap(),
+        );
+        let mut client = client(&backend);
+        client.access_token = "access".to_owned();
+
+        let page: IllustResponse = serde_json::from_str(
+            &client
+                .get_from_pixiv_raw(client.illust_recommended_first_url())
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(page.illusts.len(), 1);
+        let page: IllustResponse =
+            serde_json::from_str(&client.get_from_pixiv_raw(page.next_url.unwrap()).await.unwrap())
+                .unwrap();
+        assert!(page.illusts.is_empty());
+        assert_eq!(page.next_url, None);
+
+        let requests = backend.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].url.ends_with("offset=30"));
+        assert!(requests
+            .iter()
+            .all(|r| r.headers.contains(&("Authorization".to_owned(), "Bearer access".to_owned()))));
+    }
+
+    #[tokio::test]
+    async fn user_previews_paging() {
+        let backend = FixtureBackend::new();
+        let first = UserPreviewsResponse {
+            user_previews: vec![UserPreview::default(), UserPreview::default()],
+            next_url: Some(
+                "http://app.test/v1/user/following?filter=for_android&user_id=1&restrict=public&offset=30"
+                    .to_owned(),
+            ),
+        };
+        backend.add(
+            Method::GET,
+            "/v1/user/following",
+            200,
+            &serde_json::to_string(&first).unwrap(),
+        );
+        backend.add(
+            Method::GET,
+            "/v1/user/following?filter=for_android&user_id=1&restrict=public&offset=30",
+            200,
+            &serde_json::to_string(&UserPreviewsResponse::default()).unwrap(),
+        );
+        let client = client(&backend);
+
+        let page = client.user_following(1, Restrict::Public).await.unwrap();
+        assert_eq!(page.user_previews.len(), 2);
+        let page = client
+            .user_previews_from_url(page.next_url.unwrap())
+            .await
+            .unwrap();
+        assert!(page.user_previews.is_empty());
+        assert_eq!(page.next_url, None);
+    }
+
+    #[tokio::test]
+    async fn bookmark_and_follow_post_forms() {
+        let backend = FixtureBackend::new();
+        backend.add(Method::POST, "/", 200, "{}");
+        let client = client(&backend);
+
+        client.add_bookmark(10, Restrict::Private).await.unwrap();
+        client.delete_bookmark(10).await.unwrap();
+        client.follow_user(20, Restrict::Public).await.unwrap();
+        client.unfollow_user(20).await.unwrap();
+
+        let requests = backend.requests();
+        let sent: Vec<(&str, HashMap<String, String>)> = requests
+            .iter()
+            .map(|r| (r.url.trim_start_matches("http://app.test"), form(r)))
+            .collect();
+        let expected = |pairs: &[(&str, &str)]| -> HashMap<String, String> {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+        assert_eq!(
+            sent,
+            vec![
+                (
+                    "/v2/illust/bookmark/add",
+                    expected(&[("illust_id", "10"), ("restrict", "private")])
+                ),
+                ("/v1/illust/bookmark/delete", expected(&[("illust_id", "10")])),
+                (
+                    "/v1/user/follow/add",
+                    expected(&[("user_id", "20"), ("restrict", "public")])
+                ),
+                ("/v1/user/follow/delete", expected(&[("user_id", "20")])),
+            ]
+        );
+        assert!(requests.iter().all(|r| r.method == Method::POST));
+    }
+
+    #[tokio::test]
+    async fn error_mapping() {
+        let backend = FixtureBackend::new();
+        backend.add(
+            Method::POST,
+            "/auth/token",
+            400,
+            &serde_json::json!({
+                "has_error": true,
+                "errors": {"system": {"message": "Invalid refresh token", "code": 1508}},
+                "error": "invalid_grant",
+            })
+            .to_string(),
+        );
+        backend.add(
+            Method::GET,
+            "/v1/expired",
+            400,
+            &app_error_json("Error occurred at the OAuth process. Error Message: invalid_grant"),
+        );
+        backend.add(Method::GET, "/v1/unauthorized", 401, &app_error_json(""));
+        backend.add(Method::GET, "/v1/missing", 404, &app_error_json("Not Found"));
+        let client = client(&backend);
+
+        let err = client.refresh_token("refresh").await.unwrap_err();
+        let oauth = err.downcast_ref::<OAuthError>().unwrap();
+        assert!(oauth.is_invalid_grant());
+        assert_eq!(oauth.status, 400);
+        assert_eq!(err.to_string(), "Invalid refresh token");
+
+        for path in ["/v1/expired", "/v1/unauthorized"] {
+            let err = client
+                .get_from_pixiv_raw(format!("http://app.test{path}"))
+                .await
+                .unwrap_err();
+            assert!(err.downcast_ref::<AuthError>().is_some(), "{path}");
+        }
+
+        let err = client
+            .get_from_pixiv_raw("http://app.test/v1/missing".to_owned())
+            .await
+            .unwrap_err();
+        assert!(err.downcast_ref::<AuthError>().is_none());
+        assert_eq!(err.to_string(), "Not Found");
+
+        // 没有对应的录制响应
+        let err = client
+            .get_from_pixiv_raw("http://app.test/v2/other".to_owned())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().starts_with("no fixture for GET /v2/other"));
+    }
+
+    #[test]
+    fn loads_fixtures_from_dir() {
+        let dir = std::env::temp_dir().join(format!("pansy-fixtures-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("trending.json"),
+            r#"{"method":"GET","path":"/v1/trending-tags/illust","status":200,"body":"{\"trend_tags\":[]}"}"#,
+        )
+        .unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+        let backend = FixtureBackend::from_dir(dir.to_str().unwrap()).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let client = client(&backend);
+        let rsp = tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(client.get_from_pixiv_raw(client.illust_trending_tags_url()))
+            .unwrap();
+        assert_eq!(rsp, r#"{"trend_tags":[]}"#);
+    }
+
+    #[tokio::test]
+    async fn parses_recorded_responses() {
+        let backend = FixtureBackend::from_dir(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/pixirust/fixtures"
+        ))
+        .unwrap();
+        let client = client(&backend);
+
+        let detail: UserDetail = serde_json::from_str(
+            &client
+                .get_from_pixiv_raw(client.user_detail_url(20311846))
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(detail.user.id, 20311846);
+        assert_eq!(detail.user.account, "poyo_2021");
+        assert!(detail.user.is_followed);
+        assert_eq!(detail.profile.total_illusts, 96);
+        assert!(detail.profile.is_premium);
+        assert_eq!(detail.profile.pawoo_url, None);
+        assert_eq!(detail.workspace.tablet, "Wacom Cintiq 16");
+        assert!(!detail.stale);
+
+        let trending = client.illust_trending_tags().await.unwrap();
+        assert_eq!(trending.trend_tags.len(), 3);
+        assert_eq!(trending.trend_tags[0].tag, "オリジナル");
+        assert_eq!(trending.trend_tags[2].translated_name, None);
+        assert_eq!(trending.trend_tags[1].illust.user.name, "しろ");
+
+        let ranking: IllustResponse = serde_json::from_str(
+            &client
+                .get_from_pixiv_raw(client.illust_rank_first_url(RankMode::Day, "2024-05-17".to_owned()))
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(ranking.illusts.len(), 2);
+        assert_eq!(ranking.illusts[0].total_bookmarks, 12504);
+        assert_eq!(ranking.illusts[1].illust_type, "manga");
+        assert!(ranking.next_url.unwrap().ends_with("offset=30"));
+
+        let page: IllustResponse = serde_json::from_str(
+            &client
+                .get_from_pixiv_raw(client.user_illusts_first_url(20311846))
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        let single = &page.illusts[0];
+        assert_eq!(single.page_count, 1);
+        assert!(single.meta_pages.is_empty());
+        assert!(single
+            .meta_single_page
+            .original_image_url
+            .as_ref()
+            .unwrap()
+            .ends_with("118840213_p0.png"));
+        let multi = &page.illusts[1];
+        assert_eq!(multi.page_count, 4);
+        assert_eq!(multi.meta_single_page.original_image_url, None);
+        assert_eq!(multi.meta_pages.len(), 4);
+        assert!(multi.meta_pages[3].image_urls.original.ends_with("118612094_p3.jpg"));
+        assert_eq!(multi.illust_ai_type, 1);
+        assert_eq!(multi.tags[0].translated_name.as_deref(), Some("original"));
+    }
+}
@@ -0,0 +1,73 @@
+use anyhow::Result;
+use rustls::pki_types::ServerName;
+use rustls::RootCertStore;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::net::TcpStream;
+use tokio_rustls::TlsConnector;
+
+const PROBE_TIMEOUT_SECS: u64 = 5;
+
+/// 映射中一个 host 可以有多个候选IP, 以逗号分隔
+pub fn split_ips(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|ip| ip.trim())
+        .filter(|ip| !ip.is_empty())
+        .map(|ip| ip.to_owned())
+        .collect()
+}
+
+/// 将 host -> ip 的映射反转为 ip -> host
+pub fn hosts_by_ip(bypass_hosts: &HashMap<String, String>) -> HashMap<IpAddr, Vec<String>> {
+    let mut hosts: HashMap<IpAddr, Vec<String>> = HashMap::new();
+    for (host, ips) in bypass_hosts {
+        for ip in split_ips(ips) {
+            if let Ok(ip) = ip.parse::<IpAddr>() {
+                hosts.entry(ip).or_default().push(host.clone());
+            }
+        }
+    }
+    hosts
+}
+
+/// SNI bypass 使用的 TLS 配置: 不发送SNI, 证书仍按请求的真实域名校验
+pub fn bypass_tls_config(http2: bool) -> Result<rustls::ClientConfig> {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let roots = RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+    };
+    let mut config = rustls::ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()?
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    config.enable_sni = false;
+    config.alpn_protocols = if http2 {
+        vec![b"h2".to_vec(), b"http/1.1".to_vec()]
+    } else {
+        vec![b"http/1.1".to_vec()]
+    };
+    Ok(config)
+}
+
+/// 一次探测的耗时(毫秒)
+pub struct ProbeResult {
+    pub tcp_ms: i64,
+    pub tls_ms: i64,
+}
+
+/// 对IP的443端口建立TCP连接并以 host 的证书完成TLS握手
+pub async fn probe(config: Arc<rustls::ClientConfig>, host: &str, ip: &str) -> Result<ProbeResult> {
+    let ip: IpAddr = ip.parse()?;
+    let server_name = ServerName::try_from(host.to_owned())?;
+    let timeout = Duration::from_secs(PROBE_TIMEOUT_SECS);
+    let start = Instant::now();
+    let stream = tokio::time::timeout(timeout, TcpStream::connect((ip, 443))).await??;
+    let tcp_ms = start.elapsed().as_millis() as i64;
+    let start = Instant::now();
+    tokio::time::timeout(timeout, TlsConnector::from(config).connect(server_name, stream)).await??;
+    let tls_ms = start.elapsed().as_millis() as i64;
+    Ok(ProbeResult { tcp_ms, tls_ms })
+}
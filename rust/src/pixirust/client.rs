@@ -1,85 +1,177 @@
+use super::backend::{HttpBackend, HttpRequest, HttpResponse, ReqwestBackend};
+use super::rate_limit::{Budget, RateLimiter, API_BUDGET, IMAGE_BUDGET};
+use crate::udto::RateLimitState;
 pub use super::entities::*;
 use super::utils::*;
 pub use anyhow::Error;
 pub use anyhow::Result;
 use base64::Engine;
-use reqwest::header;
-use serde_json::json;
-
-const APP_SERVER: &'static str = "app-api.pixiv.net";
-const OAUTH_SERVER: &'static str = "oauth.secure.pixiv.net";
-const IMG_SERVER: &'static str = "i.pximg.net";
-
-struct Server {
-    pub server: &'static str,
+use reqwest::Method;
+use std::sync::Arc;
+
+pub const APP_SERVER: &str = "https://app-api.pixiv.net";
+pub const OAUTH_SERVER: &str = "https://oauth.secure.pixiv.net";
+pub const IMG_SERVER: &str = "https://i.pximg.net";
+const IMG_STATIC_SERVER: &str = "https://s.pximg.net";
+
+/// 各服务的地址(scheme + host[:port], 不以/结尾), 默认为pixiv官方地址
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServerUrls {
+    pub app: String,
+    pub oauth: String,
+    pub image: String,
 }
 
-const APP: Server = Server {
-    server: APP_SERVER,
-};
-
-const OAUTH: Server = Server {
-    server: OAUTH_SERVER,
-};
+impl Default for ServerUrls {
+    fn default() -> Self {
+        Self {
+            app: APP_SERVER.to_owned(),
+            oauth: OAUTH_SERVER.to_owned(),
+            image: IMG_SERVER.to_owned(),
+        }
+    }
+}
 
-const IMG: Server = Server {
-    server: IMG_SERVER,
-};
+/// 收到 429 后最多重试的次数
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+const DEFAULT_RETRY_AFTER_SECS: u64 = 5;
+const MAX_RETRY_AFTER_SECS: u64 = 300;
+/// GET/HEAD 收到 5xx 后重试的次数和间隔, 其他方法可能已经在服务端生效, 不重发
+const MAX_SERVER_ERROR_RETRIES: u32 = 1;
+const SERVER_ERROR_RETRY_DELAY: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Retry-After 可以是秒数或 HTTP 日期, 没有时使用默认值
+fn retry_after(rsp: &HttpResponse) -> std::time::Duration {
+    let secs = rsp
+        .header("retry-after")
+        .and_then(|value| {
+            let value = value.trim();
+            value.parse::<u64>().ok().or_else(|| {
+                let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+                Some((date.timestamp() - chrono::Utc::now().timestamp()).max(0) as u64)
+            })
+        })
+        .unwrap_or(DEFAULT_RETRY_AFTER_SECS);
+    std::time::Duration::from_secs(secs.clamp(1, MAX_RETRY_AFTER_SECS))
+}
 
 const SALT: &'static str = "28c1fdd170a5204386cb1313c7077b34f83e4aaf4aa829ce78c231e05b0bae2c";
 const CLIENT_ID: &'static str = "MOBrBDS8blbauoSck0ZfDbtuzpyT";
 const CLIENT_SECRET: &'static str = "lsACyCD94FhDUtGTXi3QzcFE2uU1hqtDaKeqrdwj";
 
+/// app-api 认为 access token 无效(过期或被吊销), 刷新token后可以重试
+#[derive(Debug)]
+pub struct AuthError {
+    pub message: String,
+}
+
+impl std::fmt::Display for AuthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for AuthError {}
+
+/// token过期时返回400: "Error occurred at the OAuth process ... invalid_grant"
+fn is_auth_error(status: u16, message: &str) -> bool {
+    status == 401
+        || (status == 400 && (message.contains("invalid_grant") || message.contains("OAuth")))
+}
+
+/// OAuth 服务返回的错误, refresh token 失效(被吊销、修改密码)时为 400 invalid_grant
+#[derive(Debug)]
+pub struct OAuthError {
+    pub status: u16,
+    pub error: String,
+    pub message: String,
+}
+
+impl OAuthError {
+    pub fn is_invalid_grant(&self) -> bool {
+        self.error == "invalid_grant"
+    }
+
+    /// 服务端拒绝了请求(4xx), 重试也不会成功
+    pub fn is_rejected(&self) -> bool {
+        self.is_invalid_grant() || (400..500).contains(&self.status)
+    }
+}
+
+impl std::fmt::Display for OAuthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for OAuthError {}
+
+/// clone 出的客户端共享传输层和限流状态
+#[derive(Clone)]
 pub struct Client {
     pub access_token: String,
-    agent: reqwest::Client,
-    agent_sni_bypass: reqwest::Client,
+    servers: ServerUrls,
+    backend: Arc<dyn HttpBackend>,
+    limiter: Arc<RateLimiter>,
 }
 
 impl Client {
     /// 创建客户端
     pub fn new() -> Self {
+        Self::with_backend(Box::new(ReqwestBackend::new()))
+    }
+
+    /// 使用指定的传输层创建客户端
+    pub fn with_backend(backend: Box<dyn HttpBackend>) -> Self {
         Self {
-            agent: reqwest::ClientBuilder::new().build().unwrap(),
-            agent_sni_bypass: reqwest::ClientBuilder::new()
-                .danger_accept_invalid_certs(true)
-                .danger_accept_invalid_hostnames(true)
-                .http1_only()
-                .build()
-                .unwrap(),
             access_token: String::default(),
+            servers: ServerUrls::default(),
+            backend: backend.into(),
+            limiter: Arc::new(RateLimiter::new()),
         }
     }
 
-    async fn build_request(
-        &self,
-        method: reqwest::Method,
-        url: &str,
-    ) -> reqwest::RequestBuilder {
-        let bypass = crate::local::get_bypass_sni_().await;
-        if !bypass {
-            return self.agent.request(method, url);
-        }
+    /// 修改服务地址, 用于镜像或本地测试服务
+    pub fn set_servers(&mut self, servers: ServerUrls) {
+        self.servers = ServerUrls {
+            app: servers.app.trim_end_matches('/').to_owned(),
+            oauth: servers.oauth.trim_end_matches('/').to_owned(),
+            image: servers.image.trim_end_matches('/').to_owned(),
+        };
+    }
 
-        let parsed = reqwest::Url::parse(url);
-        if let Ok(mut parsed) = parsed {
-            if parsed.scheme() != "https" {
-                return self.agent.request(method, parsed);
-            }
-            let host = parsed.host_str().map(|h| h.to_string());
-            if let Some(host) = host {
-                if let Some(ip) = crate::local::get_bypass_sni_ip_for_host(&host).await {
-                    if parsed.set_host(Some(ip.as_str())).is_ok() {
-                        return self
-                            .agent_sni_bypass
-                            .request(method, parsed)
-                            .header(header::HOST, host);
-                    }
-                }
+    /// 替换传输层, 例如代理设置变更后重建的 ReqwestBackend
+    pub fn set_backend(&mut self, backend: Box<dyn HttpBackend>) {
+        self.backend = backend.into();
+    }
+
+    /// 当前的传输层, 不经过签名和限流, 可以在释放客户端的锁之后使用
+    pub fn backend(&self) -> Arc<dyn HttpBackend> {
+        self.backend.clone()
+    }
+
+    pub fn rate_limit_states(&self) -> Vec<RateLimitState> {
+        self.limiter.states()
+    }
+
+    pub fn servers(&self) -> &ServerUrls {
+        &self.servers
+    }
+
+    /// 将 i.pximg.net / s.pximg.net 的图片地址替换为设置的图片服务地址(如 i.pixiv.re)
+    pub fn image_url(&self, url: String) -> String {
+        if self.servers.image == IMG_SERVER {
+            return url;
+        }
+        match url
+            .strip_prefix(IMG_SERVER)
+            .or_else(|| url.strip_prefix(IMG_STATIC_SERVER))
+        {
+            Some(path) if path.is_empty() || path.starts_with('/') => {
+                format!("{}{}", self.servers.image, path)
             }
-            return self.agent.request(method, parsed);
+            _ => url,
         }
-        self.agent.request(method, url)
     }
 
     /// pixiv的base64格式
@@ -123,56 +215,104 @@ impl Client {
     }
 
     /// 请求并获得结果
-    async fn load_token(&self, body: serde_json::Value) -> Result<Token> {
-        let url = format!("https://{}/auth/token", OAUTH.server);
-        let req = self.build_request(reqwest::Method::POST, url.as_str()).await;
-        let rsp = req.form(&body).send().await;
-        match rsp {
-            Ok(resp) => {
-                let status = resp.status();
-                match status.as_u16() {
-                    200 => Ok(serde_json::from_str(resp.text().await?.as_str())?),
-                    _ => {
-                        let err: LoginErrorResponse =
-                            serde_json::from_str(resp.text().await?.as_str())?;
-                        Err(Error::msg(err.errors.system.message))
-                    }
-                }
+    async fn load_token(&self, body: Vec<(&str, String)>) -> Result<Token> {
+        let url = format!("{}/auth/token", self.servers.oauth);
+        let rsp = self
+            .send(HttpRequest::new(Method::POST, url).form(body))
+            .await?;
+        match rsp.status {
+            200 => Ok(serde_json::from_str(rsp.text()?.as_str())?),
+            _ => {
+                let err: LoginErrorResponse = serde_json::from_str(rsp.text()?.as_str())?;
+                Err(Error::new(OAuthError {
+                    status: rsp.status,
+                    error: err.error,
+                    message: err.errors.system.message,
+                }))
             }
-            Err(err) => Err(Error::msg(err)),
         }
     }
 
     /// 使用code登录
     pub async fn load_token_by_code(&self, code: String, verify: String) -> Result<Token> {
-        self.load_token(json!({
-        "code":           code,
-        "code_verifier":  verify,
-        "redirect_uri":   "https://app-api.pixiv.net/web/v1/users/auth/pixiv/callback",
-        "grant_type":     "authorization_code",
-        "include_policy": "true",
-        "client_id":      CLIENT_ID,
-        "client_secret":  CLIENT_SECRET,
-        }))
+        self.load_token(vec![
+            ("code", code),
+            ("code_verifier", verify),
+            (
+                "redirect_uri",
+                "https://app-api.pixiv.net/web/v1/users/auth/pixiv/callback".to_owned(),
+            ),
+            ("grant_type", "authorization_code".to_owned()),
+            ("include_policy", "true".to_owned()),
+            ("client_id", CLIENT_ID.to_owned()),
+            ("client_secret", CLIENT_SECRET.to_owned()),
+        ])
         .await
     }
 
     /// 刷新token
-    pub async fn refresh_token(&self, refresh_token: &String) -> Result<Token> {
-        self.load_token(json!({
-        "refresh_token":  refresh_token,
-        "grant_type":     "refresh_token",
-        "include_policy": "true",
-        "client_id":      CLIENT_ID,
-        "client_secret":  CLIENT_SECRET,
-        }))
+    pub async fn refresh_token(&self, refresh_token: &str) -> Result<Token> {
+        self.load_token(vec![
+            ("refresh_token", refresh_token.to_owned()),
+            ("grant_type", "refresh_token".to_owned()),
+            ("include_policy", "true".to_owned()),
+            ("client_id", CLIENT_ID.to_owned()),
+            ("client_secret", CLIENT_SECRET.to_owned()),
+        ])
         .await
     }
 
-    fn sign_request(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+    /// 图片服务使用单独的限流额度
+    fn budget_for(&self, host: &str) -> Budget {
+        let image_host = reqwest::Url::parse(&self.servers.image)
+            .ok()
+            .and_then(|url| url.host_str().map(|h| h.to_owned()));
+        if image_host.as_deref() == Some(host) || host.ends_with("pximg.net") {
+            IMAGE_BUDGET
+        } else {
+            API_BUDGET
+        }
+    }
+
+    /// 经过限流发送请求, 收到 429 时按 Retry-After 暂停该host并重试,
+    /// GET/HEAD 收到 5xx 时稍后重试一次
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let host = reqwest::Url::parse(&request.url)?
+            .host_str()
+            .unwrap_or_default()
+            .to_owned();
+        let budget = self.budget_for(&host);
+        let idempotent = request.method == Method::GET || request.method == Method::HEAD;
+        let mut attempt = 0;
+        let mut server_errors = 0;
+        loop {
+            self.limiter.acquire(&host, budget).await;
+            let rsp = self.backend.send(request.clone()).await?;
+            if rsp.status >= 500 && idempotent && server_errors < MAX_SERVER_ERROR_RETRIES {
+                server_errors += 1;
+                tokio::time::sleep(SERVER_ERROR_RETRY_DELAY).await;
+                continue;
+            }
+            if rsp.status != 429 {
+                return Ok(rsp);
+            }
+            let wait = retry_after(&rsp);
+            self.limiter.pause(&host, budget, wait);
+            if attempt >= MAX_RATE_LIMIT_RETRIES {
+                return Err(Error::msg(format!(
+                    "rate limited by {}, retry after {}s",
+                    host,
+                    wait.as_secs()
+                )));
+            }
+            attempt += 1;
+        }
+    }
+
+    fn sign_request(&self, request: HttpRequest) -> HttpRequest {
         let time = self.iso_time();
         request
-            .header("x-client-time", &time.clone())
+            .header("x-client-time", time.clone())
             .header("x-client-hash", hex::encode(format!("{}{}", time, SALT)))
             .header("accept-language", "zh-CN")
             .header(
@@ -181,22 +321,34 @@ impl Client {
             )
             .header("App-OS-Version", "Android 10.0")
             .header("Referer", "https://app-api.pixiv.net/")
-            .bearer_auth(&self.access_token)
+            .header("Authorization", format!("Bearer {}", self.access_token))
+    }
+
+    /// 解析 app-api 的错误响应
+    fn app_error(&self, rsp: HttpResponse) -> Error {
+        let status = rsp.status;
+        let text = match rsp.text() {
+            Ok(text) => text,
+            Err(err) => return err,
+        };
+        let ae: AppError = match serde_json::from_str(text.as_str()) {
+            Ok(ae) => ae,
+            Err(err) => return Error::msg(err),
+        };
+        if is_auth_error(status, &ae.error.message) {
+            return Error::new(AuthError {
+                message: ae.error.message,
+            });
+        }
+        Error::msg(ae.error.message)
     }
 
     pub async fn get_from_pixiv_raw(&self, url: String) -> Result<String> {
-        let req = self.build_request(reqwest::Method::GET, url.as_str()).await;
-        let req = self.sign_request(req);
-        let rsp = req.send().await?;
-        match &rsp.status().as_u16() {
-            200 => {
-                let text = rsp.text().await?;
-                Ok(text)
-            },
-            _ => {
-                let ae: AppError = serde_json::from_str(rsp.text().await?.as_str())?;
-                Err(Error::msg(ae.error.message))
-            }
+        let req = self.sign_request(HttpRequest::new(Method::GET, url));
+        let rsp = self.send(req).await?;
+        match rsp.status {
+            200 => rsp.text(),
+            _ => Err(self.app_error(rsp)),
         }
     }
 
@@ -206,54 +358,45 @@ impl Client {
     }
 
     async fn post_form_pixiv<T: for<'de> serde::Deserialize<'de>>(&self, url: String, form: Vec<(&str, String)>) -> Result<T> {
-        let req = self.build_request(reqwest::Method::POST, url.as_str()).await;
-        let req = self.sign_request(req);
-        let rsp = req.form(&form).send().await?;
-        match &rsp.status().as_u16() {
-            200 => {
-                let text = rsp.text().await?;
-                Ok(serde_json::from_str(text.as_str())?)
-            },
-            _ => {
-                let ae: AppError = serde_json::from_str(rsp.text().await?.as_str())?;
-                Err(Error::msg(ae.error.message))
-            }
+        let req = self.sign_request(HttpRequest::new(Method::POST, url).form(form));
+        let rsp = self.send(req).await?;
+        match rsp.status {
+            200 => Ok(serde_json::from_str(rsp.text()?.as_str())?),
+            _ => Err(self.app_error(rsp)),
         }
     }
 
     pub fn illust_recommended_first_url(&self) -> String {
         format!(
-            "https://{}/v1/illust/recommended?filter=for_ios&include_ranking_label=true",
-            APP.server
+            "{}/v1/illust/recommended?filter=for_ios&include_ranking_label=true",
+            self.servers.app
         )
     }
 
-    pub fn illust_rank_first_url(&self, mode: String, date: String) -> String {
+    pub fn illust_rank_first_url(&self, mode: RankMode, date: String) -> String {
         format!(
-            "https://{}/v1/illust/ranking?filter=for_android&mode={}&date={}",
-            APP.server, mode, date,
+            "{}/v1/illust/ranking?filter=for_android&mode={}&date={}",
+            self.servers.app,
+            mode.as_str(),
+            date,
         )
     }
 
     pub fn user_illusts_first_url(&self, user_id: i64) -> String {
         format!(
-            "https://{}/v1/user/illusts?filter=for_android&user_id={}&type=illust",
-            APP.server, user_id,
+            "{}/v1/user/illusts?filter=for_android&user_id={}&type=illust",
+            self.servers.app, user_id,
         )
     }
 
-    pub async fn illust_from_url(&self, url: String) -> Result<IllustResponse> {
-        self.get_from_pixiv(url).await
-    }
-
     pub async fn user_previews_from_url(&self, url: String) -> Result<UserPreviewsResponse> {
         self.get_from_pixiv(url).await
     }
 
     pub fn illust_trending_tags_url(&self) -> String {
         format!(
-            "https://{}/v1/trending-tags/illust?filter=for_android",
-            APP.server,
+            "{}/v1/trending-tags/illust?filter=for_android",
+            self.servers.app,
         )
     }
 
@@ -261,38 +404,30 @@ impl Client {
         self.get_from_pixiv(self.illust_trending_tags_url()).await
     }
 
-    ///
-    /// mode:
-    /// partial_match_for_tags  - 标签部分一致
-    /// exact_match_for_tags    - 标签完全一致
-    /// title_and_caption       - 标题说明文
-    ///
-    /// sort: [date_desc, date_asc, popular_desc] - popular_desc为会员的热门排序
-    ///
-    pub fn illust_search_first_url(&self, word: String, search_target: String, sort: String) -> String {
+    /// 热门排序是否为会员由调用方检查
+    pub fn illust_search_first_url(&self, word: String, search_target: SearchTarget, sort: SearchSort) -> String {
         format!(
-            "https://{}/v1/search/illust?word={}&search_target={}&sort={}&merge_plain_keyword_results=true&filter=for_ios",
-            APP.server,
+            "{}/v1/search/illust?word={}&search_target={}&sort={}&merge_plain_keyword_results=true&filter=for_ios",
+            self.servers.app,
             urlencoding::encode(word.as_str()),
-            search_target,
-            sort,
+            search_target.as_str(),
+            sort.as_str(),
         )
     }
 
-    pub async fn user_detail(&self, user_id: i64) -> Result<UserDetail> {
-        self.get_from_pixiv(format!(
-            "https://{}/v1/user/detail?filter=for_android&user_id={}",
-            APP.server, user_id,
-        ))
-        .await
+    pub fn user_detail_url(&self, user_id: i64) -> String {
+        format!(
+            "{}/v1/user/detail?filter=for_android&user_id={}",
+            self.servers.app, user_id,
+        )
     }
 
-    pub async fn follow_user(&self, user_id: i64, restrict: String) -> Result<()> {
+    pub async fn follow_user(&self, user_id: i64, restrict: Restrict) -> Result<()> {
         let _result: serde_json::Value = self.post_form_pixiv(
-            format!("https://{}/v1/user/follow/add", APP.server),
+            format!("{}/v1/user/follow/add", self.servers.app),
             vec![
                 ("user_id", user_id.to_string()),
-                ("restrict", restrict),
+                ("restrict", restrict.as_str().to_owned()),
             ],
         )
         .await?;
@@ -301,36 +436,40 @@ impl Client {
 
     pub async fn unfollow_user(&self, user_id: i64) -> Result<()> {
         let _result: serde_json::Value = self.post_form_pixiv(
-            format!("https://{}/v1/user/follow/delete", APP.server),
+            format!("{}/v1/user/follow/delete", self.servers.app),
             vec![("user_id", user_id.to_string())],
         )
         .await?;
         Ok(())
     }
 
-    pub async fn user_following(&self, user_id: i64, restrict: String) -> Result<UserPreviewsResponse> {
+    pub async fn user_following(&self, user_id: i64, restrict: Restrict) -> Result<UserPreviewsResponse> {
         self.get_from_pixiv(format!(
-            "https://{}/v1/user/following?filter=for_android&user_id={}&restrict={}",
-            APP.server, user_id, restrict,
+            "{}/v1/user/following?filter=for_android&user_id={}&restrict={}",
+            self.servers.app,
+            user_id,
+            restrict.as_str(),
         ))
         .await
     }
 
-    pub async fn user_bookmarks(&self, user_id: i64, restrict: String, tag: Option<String>) -> Result<IllustResponse> {
+    pub fn user_bookmarks_url(&self, user_id: i64, restrict: Restrict, tag: Option<String>) -> String {
         let tag_param = tag.map(|t| format!("&tag={}", urlencoding::encode(&t))).unwrap_or_default();
-        self.get_from_pixiv(format!(
-            "https://{}/v1/user/bookmarks/illust?user_id={}&restrict={}{}",
-            APP.server, user_id, restrict, tag_param,
-        ))
-        .await
+        format!(
+            "{}/v1/user/bookmarks/illust?user_id={}&restrict={}{}",
+            self.servers.app,
+            user_id,
+            restrict.as_str(),
+            tag_param,
+        )
     }
 
-    pub async fn add_bookmark(&self, illust_id: i64, restrict: String) -> Result<()> {
+    pub async fn add_bookmark(&self, illust_id: i64, restrict: Restrict) -> Result<()> {
         let _result: serde_json::Value = self.post_form_pixiv(
-            format!("https://{}/v2/illust/bookmark/add", APP.server),
+            format!("{}/v2/illust/bookmark/add", self.servers.app),
             vec![
                 ("illust_id", illust_id.to_string()),
-                ("restrict", restrict),
+                ("restrict", restrict.as_str().to_owned()),
             ],
         )
         .await?;
@@ -339,7 +478,7 @@ impl Client {
 
     pub async fn delete_bookmark(&self, illust_id: i64) -> Result<()> {
         let _result: serde_json::Value = self.post_form_pixiv(
-            format!("https://{}/v1/illust/bookmark/delete", APP.server),
+            format!("{}/v1/illust/bookmark/delete", self.servers.app),
             vec![("illust_id", illust_id.to_string())],
         )
         .await?;
@@ -347,13 +486,48 @@ impl Client {
     }
 
     pub async fn load_image_data(&self, url: String) -> Result<bytes::Bytes> {
-        let req = self.build_request(reqwest::Method::GET, url.as_str()).await;
-        let req = self.sign_request(req);
-        let rsp = req.send().await?;
-        let status = rsp.status();
-        match status.as_u16() {
-            200 => Ok(rsp.bytes().await?),
-            _ => Err(Error::msg(rsp.text().await?)),
+        let req = self.sign_request(HttpRequest::new(Method::GET, self.image_url(url)));
+        let rsp = self.send(req).await?;
+        match rsp.status {
+            200 => Ok(rsp.body),
+            _ => Err(Error::msg(rsp.text()?)),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_auth_errors() {
+        assert!(is_auth_error(401, ""));
+        assert!(is_auth_error(
+            400,
+            "Error occurred at the OAuth process. Please check your Access Token to fix this. Error Message: invalid_grant",
+        ));
+        assert!(!is_auth_error(400, "Invalid illust_id"));
+        assert!(!is_auth_error(403, "Rate Limit"));
+    }
+
+    fn retry_after_secs(headers: &[(&str, &str)]) -> u64 {
+        let rsp = HttpResponse {
+            status: 429,
+            headers: headers
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            body: Default::default(),
+        };
+        retry_after(&rsp).as_secs()
+    }
+
+    #[test]
+    fn parses_retry_after() {
+        assert_eq!(retry_after_secs(&[("Retry-After", "12")]), 12);
+        assert_eq!(retry_after_secs(&[]), DEFAULT_RETRY_AFTER_SECS);
+        assert_eq!(retry_after_secs(&[("retry-after", "86400")]), MAX_RETRY_AFTER_SECS);
+        // 已经过去的时间也至少等待1秒
+        assert_eq!(retry_after_secs(&[("Retry-After", "Wed, 21 Oct 2015 07:28:00 GMT")]), 1);
+    }
+}
@@ -0,0 +1,156 @@
+use anyhow::{Error, Result};
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use serde_derive::Deserialize;
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+const DOH_TIMEOUT_SECS: u64 = 5;
+/// 缓存的最短时间, 避免 TTL 很短时频繁查询
+const MIN_CACHE_SECS: u64 = 60;
+
+pub const DEFAULT_DOH_ENDPOINTS: [&str; 2] =
+    ["https://1.1.1.1/dns-query", "https://dns.google/resolve"];
+
+#[derive(Deserialize)]
+struct DnsJson {
+    #[serde(rename = "Status")]
+    status: i32,
+    #[serde(rename = "Answer", default)]
+    answer: Vec<DnsAnswer>,
+}
+
+#[derive(Deserialize)]
+struct DnsAnswer {
+    #[serde(rename = "type")]
+    record_type: u16,
+    data: String,
+    #[serde(rename = "TTL", default)]
+    ttl: u64,
+}
+
+struct DohInner {
+    endpoints: Vec<String>,
+    http: reqwest::Client,
+    cache: Mutex<HashMap<String, (Vec<IpAddr>, Instant)>>,
+}
+
+/// DNS-over-HTTPS 解析器(JSON格式), 按顺序尝试各个服务, 全部失败时使用系统DNS
+#[derive(Clone)]
+pub struct DohResolver {
+    inner: Arc<DohInner>,
+}
+
+impl DohResolver {
+    /// proxy 与普通请求使用同一个代理, 无法直连 DoH 服务时也可以解析
+    pub fn new(endpoints: Vec<String>, proxy: Option<reqwest::Proxy>) -> Result<Self> {
+        if endpoints.is_empty() {
+            return Err(Error::msg("no doh endpoint"));
+        }
+        let mut http = reqwest::ClientBuilder::new().timeout(Duration::from_secs(DOH_TIMEOUT_SECS));
+        if let Some(proxy) = proxy {
+            http = http.proxy(proxy);
+        }
+        Ok(Self {
+            inner: Arc::new(DohInner {
+                endpoints,
+                http: http.build()?,
+                cache: Mutex::new(HashMap::new()),
+            }),
+        })
+    }
+
+    /// 只通过 DoH 查询 A 记录, 不使用系统DNS
+    pub async fn lookup(&self, host: &str) -> Result<Vec<IpAddr>> {
+        if let Some((ips, expire)) = self.inner.cache.lock().unwrap().get(host) {
+            if *expire > Instant::now() {
+                return Ok(ips.clone());
+            }
+        }
+        let mut last_error = Error::msg("no doh endpoint");
+        for endpoint in &self.inner.endpoints {
+            match self.query(endpoint, host).await {
+                Ok((ips, ttl)) => {
+                    let expire = Instant::now() + Duration::from_secs(ttl.max(MIN_CACHE_SECS));
+                    self.inner
+                        .cache
+                        .lock()
+                        .unwrap()
+                        .insert(host.to_owned(), (ips.clone(), expire));
+                    return Ok(ips);
+                }
+                Err(err) => last_error = err,
+            }
+        }
+        Err(last_error)
+    }
+
+    async fn query(&self, endpoint: &str, host: &str) -> Result<(Vec<IpAddr>, u64)> {
+        let rsp = self
+            .inner
+            .http
+            .get(endpoint)
+            .query(&[("name", host), ("type", "A")])
+            .header(reqwest::header::ACCEPT, "application/dns-json")
+            .send()
+            .await?
+            .error_for_status()?;
+        let json: DnsJson = serde_json::from_slice(&rsp.bytes().await?)?;
+        if json.status != 0 {
+            return Err(Error::msg(format!("doh status {} for {}", json.status, host)));
+        }
+        // 只取A记录, CNAME 等由服务端展开
+        let answers: Vec<&DnsAnswer> = json.answer.iter().filter(|a| a.record_type == 1).collect();
+        let ips: Vec<IpAddr> = answers.iter().filter_map(|a| a.data.parse().ok()).collect();
+        if ips.is_empty() {
+            return Err(Error::msg(format!("doh no address for {}", host)));
+        }
+        let ttl = answers.iter().map(|a| a.ttl).min().unwrap_or_default();
+        Ok((ips, ttl))
+    }
+}
+
+impl Resolve for DohResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let resolver = self.clone();
+        Box::pin(async move {
+            let host = name.as_str().to_owned();
+            let addrs: Vec<SocketAddr> = match resolver.lookup(&host).await {
+                Ok(ips) => ips.into_iter().map(|ip| SocketAddr::new(ip, 0)).collect(),
+                // DoH 不可用时退回系统解析, 每个连接都会经过这里, 不打印日志
+                Err(_) => tokio::net::lookup_host((host.as_str(), 0)).await?.collect(),
+            };
+            let addrs: Addrs = Box::new(addrs.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn lookup_through_proxy() {
+        // fake_server 作为 http 代理, doh.invalid 只能经过代理访问
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy = format!("http://{}", listener.local_addr().unwrap());
+        let fixtures = vec![fake_server::Fixture {
+            method: "GET".to_owned(),
+            path: "/dns-query?name=app-api.pixiv.net".to_owned(),
+            status: 200,
+            body: r#"{"Status":0,"Answer":[{"type":5,"data":"x.","TTL":60},{"type":1,"data":"10.0.0.1","TTL":300}]}"#
+                .to_owned(),
+        }];
+        tokio::spawn(fake_server::serve(listener, Default::default(), fixtures));
+
+        let resolver = DohResolver::new(
+            vec!["http://doh.invalid/dns-query".to_owned()],
+            Some(reqwest::Proxy::all(proxy).unwrap()),
+        )
+        .unwrap();
+        let ips = resolver.lookup("app-api.pixiv.net").await.unwrap();
+        assert_eq!(ips, vec!["10.0.0.1".parse::<IpAddr>().unwrap()]);
+    }
+}
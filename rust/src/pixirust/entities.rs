@@ -75,6 +75,12 @@ pub struct IllustResponse {
     pub illusts: Vec<Illust>,
     #[serde(default)]
     pub next_url: Option<String>,
+    // 被本地屏蔽规则隐藏的作品数量
+    #[serde(default)]
+    pub hidden_count: i64,
+    // 网络失败时返回的过期缓存
+    #[serde(default)]
+    pub stale: bool,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -103,6 +109,9 @@ pub struct Illust {
     pub is_bookmarked: bool,
     pub visible: bool,
     pub is_muted: bool,
+    // 0: 未设置, 1: 非AI生成, 2: AI生成
+    #[serde(default)]
+    pub illust_ai_type: i64,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -168,12 +177,131 @@ pub struct TrendTag {
     pub illust: Illust,
 }
 
-#[allow(dead_code)]
-pub const ILLUST_SEARCH_MODE_PARTIAL_MATCH_FOR_TAGS: &'static str = "partial_match_for_tags";
-#[allow(dead_code)]
-pub const ILLUST_SEARCH_MODE_EXACT_MATCH_FOR_TAGS: &'static str = "exact_match_for_tags";
-#[allow(dead_code)]
-pub const ILLUST_SEARCH_MODE_TITLE_AND_CAPTION: &'static str = "title_and_caption";
+/// 排行榜类型, r18 / r18g 需要账号开启对应的浏览设置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RankMode {
+    Day,
+    Week,
+    Month,
+    DayMale,
+    DayFemale,
+    WeekOriginal,
+    WeekRookie,
+    DayAi,
+    DayManga,
+    WeekManga,
+    MonthManga,
+    WeekRookieManga,
+    DayR18,
+    DayMaleR18,
+    DayFemaleR18,
+    WeekR18,
+    #[serde(rename = "week_r18g")]
+    WeekR18G,
+    DayR18Ai,
+    DayR18Manga,
+    WeekR18Manga,
+    #[serde(rename = "week_r18g_manga")]
+    WeekR18GManga,
+}
+
+impl RankMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RankMode::Day => "day",
+            RankMode::Week => "week",
+            RankMode::Month => "month",
+            RankMode::DayMale => "day_male",
+            RankMode::DayFemale => "day_female",
+            RankMode::WeekOriginal => "week_original",
+            RankMode::WeekRookie => "week_rookie",
+            RankMode::DayAi => "day_ai",
+            RankMode::DayManga => "day_manga",
+            RankMode::WeekManga => "week_manga",
+            RankMode::MonthManga => "month_manga",
+            RankMode::WeekRookieManga => "week_rookie_manga",
+            RankMode::DayR18 => "day_r18",
+            RankMode::DayMaleR18 => "day_male_r18",
+            RankMode::DayFemaleR18 => "day_female_r18",
+            RankMode::WeekR18 => "week_r18",
+            RankMode::WeekR18G => "week_r18g",
+            RankMode::DayR18Ai => "day_r18_ai",
+            RankMode::DayR18Manga => "day_r18_manga",
+            RankMode::WeekR18Manga => "week_r18_manga",
+            RankMode::WeekR18GManga => "week_r18g_manga",
+        }
+    }
+}
+
+/// 收藏 / 关注的公开范围
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Restrict {
+    Public,
+    Private,
+}
+
+impl Restrict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Restrict::Public => "public",
+            Restrict::Private => "private",
+        }
+    }
+}
+
+/// 搜索范围
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchTarget {
+    /// 标签部分一致
+    PartialMatchForTags,
+    /// 标签完全一致
+    ExactMatchForTags,
+    /// 标题说明文
+    TitleAndCaption,
+}
+
+impl SearchTarget {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchTarget::PartialMatchForTags => "partial_match_for_tags",
+            SearchTarget::ExactMatchForTags => "exact_match_for_tags",
+            SearchTarget::TitleAndCaption => "title_and_caption",
+        }
+    }
+}
+
+/// 搜索排序, 热门排序只有会员可以使用
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchSort {
+    DateDesc,
+    DateAsc,
+    PopularDesc,
+    PopularMaleDesc,
+    PopularFemaleDesc,
+}
+
+impl SearchSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchSort::DateDesc => "date_desc",
+            SearchSort::DateAsc => "date_asc",
+            SearchSort::PopularDesc => "popular_desc",
+            SearchSort::PopularMaleDesc => "popular_male_desc",
+            SearchSort::PopularFemaleDesc => "popular_female_desc",
+        }
+    }
+
+    pub fn is_premium_only(&self) -> bool {
+        matches!(
+            self,
+            SearchSort::PopularDesc | SearchSort::PopularMaleDesc | SearchSort::PopularFemaleDesc
+        )
+    }
+}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UserDetail {
@@ -181,6 +309,9 @@ pub struct UserDetail {
     pub profile_publicity: ProfilePublicity,
     pub user: User,
     pub workspace: Workspace,
+    // 网络失败时返回的过期缓存
+    #[serde(default)]
+    pub stale: bool,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
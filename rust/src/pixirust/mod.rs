@@ -1,3 +1,7 @@
+pub mod backend;
+pub mod bypass_tls;
 pub mod client;
+pub mod doh;
 pub mod entities;
+pub mod rate_limit;
 pub mod utils;
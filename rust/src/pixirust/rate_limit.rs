@@ -0,0 +1,220 @@
+use crate::udto::RateLimitState;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// 令牌桶的容量和每秒补充的数量
+#[derive(Debug, Clone, Copy)]
+pub struct Budget {
+    pub capacity: f64,
+    pub per_sec: f64,
+}
+
+/// app-api / oauth
+pub const API_BUDGET: Budget = Budget {
+    capacity: 10.0,
+    per_sec: 3.0,
+};
+
+/// 图片服务, 列表页一次会加载几十张缩略图
+pub const IMAGE_BUDGET: Budget = Budget {
+    capacity: 40.0,
+    per_sec: 20.0,
+};
+
+struct Bucket {
+    budget: Budget,
+    tokens: f64,
+    updated: Instant,
+    /// 收到 429 后暂停到该时间
+    paused_until: Option<Instant>,
+    waiting: usize,
+    throttled_count: u64,
+}
+
+impl Bucket {
+    fn new(budget: Budget) -> Self {
+        Self {
+            budget,
+            tokens: budget.capacity,
+            updated: Instant::now(),
+            paused_until: None,
+            waiting: 0,
+            throttled_count: 0,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        // 暂停期间不补充, 避免暂停结束后立即突发请求
+        if let Some(until) = self.paused_until {
+            if until > now {
+                self.tokens = 0.0;
+                self.updated = now;
+                return;
+            }
+            if until > self.updated {
+                self.updated = until;
+            }
+        }
+        let elapsed = now.duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.budget.per_sec).min(self.budget.capacity);
+        self.updated = now;
+    }
+}
+
+/// 按host区分的令牌桶限流
+pub struct RateLimiter {
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+/// 等待中的请求计数, 请求被取消(future被丢弃)时也能减掉
+struct WaitingGuard<'a> {
+    limiter: &'a RateLimiter,
+    host: &'a str,
+}
+
+impl Drop for WaitingGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(bucket) = self.limiter.buckets.lock().unwrap().get_mut(self.host) {
+            bucket.waiting -= 1;
+        }
+    }
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self {
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 取得一个令牌, 令牌不足或处于暂停中时等待
+    pub async fn acquire(&self, host: &str, budget: Budget) {
+        let mut guard: Option<WaitingGuard> = None;
+        loop {
+            let wait = {
+                let mut buckets = self.buckets.lock().unwrap();
+                let bucket = buckets
+                    .entry(host.to_owned())
+                    .or_insert_with(|| Bucket::new(budget));
+                let now = Instant::now();
+                bucket.refill(now);
+                match bucket.paused_until {
+                    Some(until) if until > now => until - now,
+                    _ => {
+                        bucket.paused_until = None;
+                        if bucket.tokens >= 1.0 {
+                            bucket.tokens -= 1.0;
+                            return;
+                        }
+                        Duration::from_secs_f64((1.0 - bucket.tokens) / bucket.budget.per_sec)
+                    }
+                }
+            };
+            if guard.is_none() {
+                if let Some(bucket) = self.buckets.lock().unwrap().get_mut(host) {
+                    bucket.waiting += 1;
+                }
+                guard = Some(WaitingGuard {
+                    limiter: self,
+                    host,
+                });
+            }
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// 服务端返回 429 时暂停该host的所有请求
+    pub fn pause(&self, host: &str, budget: Budget, duration: Duration) {
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets
+            .entry(host.to_owned())
+            .or_insert_with(|| Bucket::new(budget));
+        let until = Instant::now() + duration;
+        if bucket.paused_until.is_none_or(|current| current < until) {
+            bucket.paused_until = Some(until);
+        }
+        bucket.tokens = 0.0;
+        bucket.throttled_count += 1;
+    }
+
+    pub fn states(&self) -> Vec<RateLimitState> {
+        let mut buckets = self.buckets.lock().unwrap();
+        let now = Instant::now();
+        let mut states: Vec<RateLimitState> = buckets
+            .iter_mut()
+            .map(|(host, bucket)| {
+                bucket.refill(now);
+                RateLimitState {
+                    host: host.clone(),
+                    available: bucket.tokens.floor() as i64,
+                    capacity: bucket.budget.capacity as i64,
+                    waiting: bucket.waiting as i64,
+                    paused_ms: bucket
+                        .paused_until
+                        .map(|until| until.saturating_duration_since(now).as_millis() as i64)
+                        .unwrap_or_default(),
+                    throttled_count: bucket.throttled_count as i64,
+                }
+            })
+            .collect();
+        states.sort_by(|a, b| a.host.cmp(&b.host));
+        states
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUDGET: Budget = Budget {
+        capacity: 2.0,
+        per_sec: 20.0,
+    };
+
+    #[tokio::test]
+    async fn waits_for_refill() {
+        let limiter = RateLimiter::new();
+        let start = Instant::now();
+        limiter.acquire("a", BUDGET).await;
+        limiter.acquire("a", BUDGET).await;
+        assert!(start.elapsed() < Duration::from_millis(20));
+        // 令牌用完后按每秒20个补充
+        limiter.acquire("a", BUDGET).await;
+        assert!(start.elapsed() >= Duration::from_millis(40));
+        // 每个host有自己的令牌桶
+        let start = Instant::now();
+        limiter.acquire("b", BUDGET).await;
+        assert!(start.elapsed() < Duration::from_millis(20));
+    }
+
+    #[tokio::test]
+    async fn pause_stops_refill() {
+        let limiter = RateLimiter::new();
+        limiter.pause("a", BUDGET, Duration::from_millis(100));
+        let state = &limiter.states()[0];
+        assert_eq!(state.available, 0);
+        assert_eq!(state.throttled_count, 1);
+        assert!(state.paused_ms > 0);
+        let start = Instant::now();
+        limiter.acquire("a", BUDGET).await;
+        // 暂停结束后从0开始补充
+        assert!(start.elapsed() >= Duration::from_millis(140));
+    }
+
+    #[tokio::test]
+    async fn dropped_waiter_is_not_counted() {
+        let limiter = std::sync::Arc::new(RateLimiter::new());
+        limiter.pause("a", BUDGET, Duration::from_secs(10));
+        let waiter = tokio::spawn({
+            let limiter = limiter.clone();
+            async move { limiter.acquire("a", BUDGET).await }
+        });
+        while limiter.states()[0].waiting == 0 {
+            tokio::task::yield_now().await;
+        }
+        waiter.abort();
+        let _ = waiter.await;
+        assert_eq!(limiter.states()[0].waiting, 0);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::pixirust::doh::DEFAULT_DOH_ENDPOINTS;
+use crate::pixirust::entities::{Illust, RankMode, SearchSort, SearchTarget};
 use serde_derive::*;
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -9,13 +11,13 @@ pub struct UiLoginByCodeQuery {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct UiIllustSearchQuery {
     pub word: String,
-    pub search_target: String,
-    pub sort: String,
+    pub search_target: SearchTarget,
+    pub sort: SearchSort,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct UiIllustRankQuery {
-    pub mode: String,
+    pub mode: RankMode,
     pub date: String,
 }
 
@@ -28,6 +30,45 @@ pub struct UiCurrentUser {
     pub is_premium: bool,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UiAccount {
+    pub user_id: i64,
+    pub name: String,
+    pub account: String,
+    pub profile_image_url: String,
+    pub is_premium: bool,
+    pub is_current: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum AuthEventKind {
+    LoggedIn,
+    TokenRefreshed,
+    /// OAuth 服务拒绝了刷新(invalid_grant 等4xx), 例如 refresh token 已在服务端被吊销;
+    /// 网络错误和5xx只返回错误, 不推送该事件
+    RefreshFailed,
+    LoggedOut,
+    /// 保存的账号无法解密(密钥文件丢失或损坏), 原数据已另存, 需要重新登录
+    AccountsUnreadable,
+}
+
+/// 登录状态变化, 通过 auth_event_stream 推送给UI
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AuthEvent {
+    pub kind: AuthEventKind,
+    /// RefreshFailed / AccountsUnreadable 时的原因, 其他情况为空
+    pub reason: String,
+}
+
+impl From<AuthEventKind> for AuthEvent {
+    fn from(kind: AuthEventKind) -> Self {
+        AuthEvent {
+            kind,
+            reason: String::new(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DownloadTaskDto {
     pub id: i64,
@@ -46,4 +87,185 @@ pub struct DownloadTaskDto {
     pub updated_time: i64,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SimilarImageDto {
+    pub url: String,
+    pub path: String,
+    pub distance: u32,
+    pub downloaded: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ImagePlaceholderDto {
+    pub url: String,
+    pub blurhash: String,
+    pub palette: Vec<u32>,
+    pub width: i32,
+    pub height: i32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ViewHistoryDto {
+    pub illust: Illust,
+    pub view_count: i32,
+    pub first_view_time: i64,
+    pub last_view_time: i64,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct IllustFilter {
+    #[serde(default)]
+    pub muted_tags: Vec<String>,
+    #[serde(default)]
+    pub muted_user_ids: Vec<i64>,
+    #[serde(default)]
+    pub hide_ai: bool,
+    #[serde(default)]
+    pub hide_r18: bool,
+    #[serde(default)]
+    pub hide_ugoira: bool,
+}
+
+/// 代理设置, url 为空表示不使用代理
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ProxySettings {
+    /// http / https / socks5 / socks5h://host:port
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub username: String,
+    #[serde(default)]
+    pub password: String,
+    /// 不走代理的地址, 逗号分隔, 例如 "localhost,127.0.0.1,.example.com"
+    #[serde(default)]
+    pub no_proxy: String,
+}
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProxyTestResult {
+    pub url: String,
+    /// 请求失败时为 -1
+    pub latency_ms: i64,
+    pub status: i32,
+    pub error: String,
+}
+
+/// DNS-over-HTTPS 设置
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct DohSettings {
+    pub enabled: bool,
+    /// JSON 格式的 DoH 地址, 按顺序尝试
+    pub endpoints: Vec<String>,
+    /// 使用 DoH 的结果更新 SNI bypass 的IP
+    pub update_bypass_hosts: bool,
+}
+
+impl Default for DohSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            endpoints: DEFAULT_DOH_ENDPOINTS.iter().map(|e| e.to_string()).collect(),
+            update_bypass_hosts: false,
+        }
+    }
+}
+
+/// SNI bypass 候选IP的状态
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BypassSniIpStatus {
+    pub host: String,
+    pub ip: String,
+    /// 当前请求优先使用的IP
+    pub selected: bool,
+    pub checked: bool,
+    pub healthy: bool,
+    /// 失败时为 -1
+    pub tcp_ms: i64,
+    pub tls_ms: i64,
+    pub error: String,
+    pub checked_time: i64,
+}
+
+/// 网络连接设置, 同时用于普通请求和 SNI bypass
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ConnectionProfile {
+    pub connect_timeout_secs: u64,
+    /// 两次读取之间的最长等待
+    pub read_timeout_secs: u64,
+    /// 整个请求的超时, 0 表示不限制(下载大图时可能很久)
+    pub total_timeout_secs: u64,
+    pub pool_max_idle_per_host: usize,
+    pub pool_idle_timeout_secs: u64,
+    /// 0 表示不开启
+    pub tcp_keepalive_secs: u64,
+    /// 通过 ALPN 协商 HTTP/2
+    pub http2: bool,
+    /// gzip / brotli
+    pub compression: bool,
+}
+
+impl Default for ConnectionProfile {
+    fn default() -> Self {
+        Self {
+            connect_timeout_secs: 10,
+            read_timeout_secs: 30,
+            total_timeout_secs: 0,
+            pool_max_idle_per_host: 16,
+            pool_idle_timeout_secs: 90,
+            tcp_keepalive_secs: 60,
+            http2: true,
+            compression: true,
+        }
+    }
+}
+
+/// 某个host的限流状态
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RateLimitState {
+    pub host: String,
+    /// 当前可立即发出的请求数
+    pub available: i64,
+    pub capacity: i64,
+    /// 正在排队等待的请求数
+    pub waiting: i64,
+    /// 收到 429 后剩余的暂停时间
+    pub paused_ms: i64,
+    /// 累计收到 429 的次数
+    pub throttled_count: i64,
+}
+
+/// 排行榜快照中的一条记录
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RankingEntryDto {
+    pub mode: RankMode,
+    pub date: String,
+    /// 从1开始
+    pub rank: i64,
+    pub illust: Illust,
+    pub captured_time: i64,
+}
+
+/// 作品在两天排行榜之间的变化, 不在榜上时名次为 None
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RankingMoveDto {
+    pub illust: Illust,
+    pub from_rank: Option<i64>,
+    pub to_rank: Option<i64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RankingDiff {
+    pub mode: RankMode,
+    pub from_date: String,
+    pub to_date: String,
+    /// 新上榜, 按名次排序
+    pub new_entries: Vec<RankingMoveDto>,
+    /// 名次上升, 上升多的在前
+    pub climbed: Vec<RankingMoveDto>,
+    /// 名次下降, 下降多的在前
+    pub fell: Vec<RankingMoveDto>,
+    /// 落榜, 按原名次排序
+    pub dropped: Vec<RankingMoveDto>,
+}